@@ -0,0 +1,47 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// admin allowlist a prize collection
+#[derive(Accounts)]
+#[instruction(args: AddPrizeCollectionArgs)]
+pub struct AddPrizeCollection<'info> {
+    /// admin wallet
+    #[account(mut)]
+    pub admin_wallet: Signer<'info>,
+
+    /// prize collection pda
+    #[account(
+        init,
+        seeds = [PRIZE_COLLECTION.as_ref(), &args.creator.to_bytes()],
+        bump,
+        payer = admin_wallet,
+        space = 8 + size_of::<PrizeCollection>(),
+    )]
+    pub prize_collection: Box<Account<'info, PrizeCollection>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.admin_wallet == admin_wallet.key() @ WinError::AccessDenied
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddPrizeCollection<'info> {
+    pub fn process(&mut self, bump: u8, args: AddPrizeCollectionArgs) -> Result<()> {
+        self.prize_collection.creator = args.creator;
+        self.prize_collection.bump = bump;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct AddPrizeCollectionArgs {
+    /// verified collection creator
+    pub creator: Pubkey,
+}
@@ -0,0 +1,68 @@
+use crate::globals::*;
+use anchor_lang::{prelude::*, solana_program::clock};
+use std::mem::size_of;
+
+/// admin create jackpot of a payment mint
+#[derive(Accounts)]
+#[instruction(args: CreateJackpotArgs)]
+pub struct CreateJackpot<'info> {
+    /// admin wallet
+    #[account(mut)]
+    pub admin_wallet: Signer<'info>,
+
+    /// jackpot pda
+    #[account(
+        init,
+        seeds = [JACKPOT.as_ref(), &args.mint.to_bytes()],
+        bump,
+        payer = admin_wallet,
+        space = 8 + size_of::<Jackpot>(),
+    )]
+    pub jackpot: Box<Account<'info, Jackpot>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.admin_wallet == admin_wallet.key() @ WinError::AccessDenied
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateJackpot<'info> {
+    pub fn process(&mut self, bump: u8, args: CreateJackpotArgs) -> Result<()> {
+        if args.coin_type == CoinType::SOL && args.mint != Pubkey::default() {
+            return Err(error!(WinError::InvalidArgs));
+        }
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+
+        self.jackpot.mint = args.mint;
+        self.jackpot.coin_type = args.coin_type;
+        self.jackpot.balance = 0;
+        self.jackpot.epoch = 1;
+        self.jackpot.epoch_start = current_time;
+        self.jackpot.entry_count = 0;
+        self.jackpot.draw_commitment = args.draw_commitment;
+        self.jackpot.payout_epoch = 0;
+        self.jackpot.winning_entry = 0;
+        self.jackpot.pending_payout = 0;
+        self.jackpot.draw_target_slot = 0;
+        self.jackpot.bump = bump;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateJackpotArgs {
+    /// payment mint; default for SOL
+    pub mint: Pubkey,
+
+    /// coin type
+    pub coin_type: CoinType,
+
+    /// bot draw commitment of the first epoch
+    pub draw_commitment: [u8;32],
+}
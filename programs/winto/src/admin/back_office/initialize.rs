@@ -96,11 +96,19 @@ impl<'info> Initialize<'info> {
         self.win.sol_amount_for_bonus_tickets = 0;
         self.win.token_amount_for_bonus_tickets = 0;
         self.win.game_bonus_ticket_amount = 0;
+        self.win.community_fee = args.community_fee;
         self.win.freely_ticket_nft_creators = args.freely_ticket_nft_creators;
         self.win.freely_ticket_amount = [0,0,0];
         self.win.freely_ticket_nft_staking_lock_period = [0,0,0];
-        self.win.community_fee = 0;
         self.win.is_emergency_flag = false;
+        self.win.max_game_extension = 0;
+        self.win.game_extension_grace_period = 0;
+        self.win.extension_refund_window = 0;
+        self.win.jackpot_fee_bps = 0;
+        self.win.jackpot_epoch_duration = 0;
+        self.win.require_prize_collection = false;
+        self.win.wings_tiers = [WingsTierConfig::default(); WINGS_TIER_COUNT];
+        self.win.seed_wings_tier_fees()?;
         self.win.bump = bump;
         Ok(())
     }
@@ -122,4 +130,7 @@ pub struct InitializeArgs {
 
     /// freely nft creator
     pub freely_ticket_nft_creators: [Pubkey; 3],
+
+    /// community fee; seeds the Wings tier fee schedule
+    pub community_fee: u16,
 }
\ No newline at end of file
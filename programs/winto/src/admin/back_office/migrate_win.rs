@@ -0,0 +1,43 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// admin migrate Win details PDA to the layout with the Wings tier table
+#[derive(Accounts)]
+pub struct MigrateWin<'info> {
+    /// admin wallet
+    #[account(mut)]
+    pub admin_wallet: Signer<'info>,
+
+    /// Win details PDA in the old layout
+    /// CHECK: verified in process
+    #[account(
+        mut,
+        seeds = [WIN.as_ref()],
+        bump,
+    )]
+    pub win: UncheckedAccount<'info>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateWin<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        migrate_account::<Win>(
+            &self.win,
+            &self.admin_wallet,
+            &self.system_program,
+            8 + size_of::<Win>(),
+        )?;
+
+        // appended settings read as zero: no extensions, no jackpot slice, no collection allowlist
+        let mut win: Account<Win> = Account::try_from(&self.win)?;
+        if win.admin_wallet != self.admin_wallet.key() {
+            return Err(error!(WinError::AccessDenied));
+        }
+        win.seed_wings_tier_fees()?;
+        win.exit(&crate::ID)?;
+        Ok(())
+    }
+}
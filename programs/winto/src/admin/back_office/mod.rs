@@ -6,6 +6,11 @@ pub mod withdraw_pda_token;
 pub mod update_by_dev;
 pub mod update_by_admin;
 pub mod withdraw_from_pda;
+pub mod create_jackpot;
+pub mod add_prize_collection;
+pub mod remove_prize_collection;
+pub mod update_wings_tiers;
+pub mod migrate_win;
 
 pub use assets_distribution::*;
 pub use initialize::*;
@@ -14,4 +19,9 @@ pub use withdraw_pda_sol::*;
 pub use withdraw_pda_token::*;
 pub use update_by_dev::*;
 pub use update_by_admin::*;
-pub use withdraw_from_pda::*;
\ No newline at end of file
+pub use withdraw_from_pda::*;
+pub use create_jackpot::*;
+pub use add_prize_collection::*;
+pub use remove_prize_collection::*;
+pub use update_wings_tiers::*;
+pub use migrate_win::*;
\ No newline at end of file
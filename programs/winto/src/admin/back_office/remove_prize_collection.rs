@@ -0,0 +1,33 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// admin remove a prize collection from the allowlist
+#[derive(Accounts)]
+pub struct RemovePrizeCollection<'info> {
+    /// admin wallet
+    #[account(mut)]
+    pub admin_wallet: Signer<'info>,
+
+    /// prize collection pda
+    #[account(
+        mut,
+        close = admin_wallet,
+        seeds = [PRIZE_COLLECTION.as_ref(), &prize_collection.creator.to_bytes()],
+        bump = prize_collection.bump,
+    )]
+    pub prize_collection: Box<Account<'info, PrizeCollection>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.admin_wallet == admin_wallet.key() @ WinError::AccessDenied
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> RemovePrizeCollection<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -28,6 +28,9 @@ impl<'info> UpdateByAdminWallet<'info> {
                 return Err(error!(WinError::InvalidArgs));
             }
         }
+        if args.jackpot_fee_bps > MAX_JACKPOT_FEE_BPS {
+            return Err(error!(WinError::InvalidArgs));
+        }
         self.win.fund_wallet = args.fund_wallet;
         self.win.wings_creator = args.wings_creator;
         self.win.sol_amount_for_bonus_tickets = args.sol_amount_for_bonus_tickets;
@@ -36,8 +39,13 @@ impl<'info> UpdateByAdminWallet<'info> {
         self.win.freely_ticket_nft_creators = args.freely_ticket_nft_creators;
         self.win.freely_ticket_amount = args.freely_ticket_amount;
         self.win.freely_ticket_nft_staking_lock_period = args.freely_ticket_nft_staking_lock_period;
-        self.win.community_fee = args.community_fee;
         self.win.is_emergency_flag = args.is_emergency_flag;
+        self.win.max_game_extension = args.max_game_extension;
+        self.win.game_extension_grace_period = args.game_extension_grace_period;
+        self.win.extension_refund_window = args.extension_refund_window;
+        self.win.jackpot_fee_bps = args.jackpot_fee_bps;
+        self.win.jackpot_epoch_duration = args.jackpot_epoch_duration;
+        self.win.require_prize_collection = args.require_prize_collection;
         Ok(())
     }
 }
@@ -68,9 +76,24 @@ pub struct UpdateByAdminWalletArgs {
     /// staking locked period time
     pub freely_ticket_nft_staking_lock_period: [u8;3],
 
-    /// community fee
-    pub community_fee: u16,
-
     /// emergency flag
     pub is_emergency_flag: bool,
+
+    /// max total game extension
+    pub max_game_extension: u32,
+
+    /// game extension grace period after end time
+    pub game_extension_grace_period: u32,
+
+    /// refund window after game extension
+    pub extension_refund_window: u32,
+
+    /// jackpot fee
+    pub jackpot_fee_bps: u16,
+
+    /// jackpot epoch duration
+    pub jackpot_epoch_duration: u32,
+
+    /// require prize NFTs from allowlisted collections
+    pub require_prize_collection: bool,
 }
@@ -0,0 +1,45 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// admin update Wings tier registry, fee schedule and perks
+#[derive(Accounts)]
+pub struct UpdateWingsTiers<'info> {
+    /// admin wallet
+    pub admin_wallet: Signer<'info>,
+
+    /// Win details PDA
+    #[account(
+        mut,
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.admin_wallet == admin_wallet.key() @ WinError::AccessDenied
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> UpdateWingsTiers<'info> {
+    pub fn process(&mut self, args: UpdateWingsTiersArgs) -> Result<()> {
+        for tier in args.wings_tiers.iter() {
+            if tier.fee_bps > 10000 {
+                return Err(error!(WinError::InvalidArgs));
+            }
+        }
+        // a creator can only classify one tier
+        for i in 0..WINGS_TIER_COUNT {
+            for j in (i + 1)..WINGS_TIER_COUNT {
+                let creator = args.wings_tiers[i].creator;
+                if creator != Pubkey::default() && creator == args.wings_tiers[j].creator {
+                    return Err(error!(WinError::InvalidArgs));
+                }
+            }
+        }
+        self.win.wings_tiers = args.wings_tiers;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct UpdateWingsTiersArgs {
+    /// tier configs indexed by WingsType
+    pub wings_tiers: [WingsTierConfig; WINGS_TIER_COUNT],
+}
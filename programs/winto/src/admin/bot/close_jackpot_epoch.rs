@@ -0,0 +1,44 @@
+use crate::globals::*;
+use anchor_lang::{prelude::*, solana_program::clock};
+
+/// bot closes an ended jackpot epoch and fixes the slot whose hash seeds its draw;
+/// an epoch whose draw slot aged out before the reveal can be closed again
+#[derive(Accounts)]
+pub struct CloseJackpotEpoch<'info> {
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// jackpot pda
+    #[account(
+        mut,
+        seeds = [JACKPOT.as_ref(), &jackpot.mint.to_bytes()],
+        bump = jackpot.bump,
+    )]
+    pub jackpot: Box<Account<'info, Jackpot>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> CloseJackpotEpoch<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let clock = clock::Clock::get().unwrap();
+        let current_time = clock.unix_timestamp as u32;
+        if current_time < self.jackpot.epoch_start.checked_add(self.win.jackpot_epoch_duration).ok_or(WinError::NumericalOverflow)? {
+            return Err(error!(WinError::JackpotEpochNotEnded));
+        }
+        if self.jackpot.draw_target_slot != 0 && !is_draw_slot_expired(self.jackpot.draw_target_slot, clock.slot) {
+            return Err(error!(WinError::AlreadyDrawn));
+        }
+        self.jackpot.draw_target_slot = clock.slot.checked_add(DRAW_SLOT_DELAY).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+}
@@ -54,6 +54,7 @@ impl<'info> CreateUserDetailsByBot<'info> {
         self.user_details.last_game_distribute_time = clock::Clock::get().unwrap().unix_timestamp as u32;
         self.user_details.freely_nft_type = 0;
         self.user_details.wings_locked_count = 0;
+        self.user_details.next_game_nonce = 0;
         self.user_details.is_freely_nft_staked = false;
         self.user_details.bump = bump;
         Ok(())
@@ -1,7 +1,19 @@
 pub mod airdrop_token;
 pub mod create_user_details_by_bot;
 pub mod update_user_reward;
+pub mod reveal_game_draw;
+pub mod record_game_winner;
+pub mod register_game_entrant;
+pub mod reveal_jackpot_draw;
+pub mod close_jackpot_epoch;
+pub mod return_legacy_game_prize;
 
 pub use airdrop_token::*;
 pub use create_user_details_by_bot::*;
-pub use update_user_reward::*;
\ No newline at end of file
+pub use update_user_reward::*;
+pub use reveal_game_draw::*;
+pub use record_game_winner::*;
+pub use register_game_entrant::*;
+pub use reveal_jackpot_draw::*;
+pub use close_jackpot_epoch::*;
+pub use return_legacy_game_prize::*;
\ No newline at end of file
@@ -0,0 +1,77 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// record the owner of the next winning entry as a winner of a drawn game
+#[derive(Accounts)]
+pub struct RecordGameWinner<'info> {
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.status == GameStatus::Settled @ WinError::NotDrawnYet,
+        constraint = game.recorded_winner_count < game.winner_count @ WinError::WinnerAlreadyRecorded,
+        constraint = game.registered_entrant_count == game.entrant_count @ WinError::EntrantsNotRegistered,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda holding the winner of the next winner slot
+    #[account(
+        mut,
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game.recorded_winner_count.to_le_bytes()],
+        bump = game_prize.bump,
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// global bid PDA whose wallet range covers the winning entry
+    #[account(
+        mut,
+        seeds = [USER_BID.as_ref(), b"1".as_ref(), &user_global_bid.user_wallet.to_bytes(), &game.key().to_bytes()],
+        bump = user_global_bid.bump,
+        constraint = user_global_bid.is_winner == false @ WinError::WinnerAlreadyRecorded,
+    )]
+    pub user_global_bid: Box<Account<'info, UserBid>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> RecordGameWinner<'info> {
+    pub fn process(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        // winners are drawn without replacement: the global bids of the earlier winners, by winner slot,
+        // take their ranges out of the layout before the next entry is drawn
+        if remaining_accounts.len() != self.game.recorded_winner_count as usize {
+            return Err(error!(WinError::InvalidArgs));
+        }
+        let mut taken = Vec::with_capacity(remaining_accounts.len());
+        for (i, account) in remaining_accounts.iter().enumerate() {
+            let winner_bid: Account<UserBid> = Account::try_from(account)?;
+            if winner_bid.game_pda_address != self.game.key() || !winner_bid.is_winner || winner_bid.winner_slot != i as u32 {
+                return Err(error!(WinError::InvalidAccount));
+            }
+            taken.push((winner_bid.entry_start_index, winner_bid.draw_weight()));
+        }
+        let entry_index = skip_taken_entries(self.game.draw_winner_entry(self.game.recorded_winner_count)?, &mut taken)?;
+        if !self.user_global_bid.covers_entry(entry_index) {
+            return Err(error!(WinError::NotWinningBid));
+        }
+
+        // prizes of the same winner slot read the winner from this prize
+        self.game_prize.winner_wallet = self.user_global_bid.user_wallet;
+        self.user_global_bid.is_winner = true;
+        self.user_global_bid.winner_slot = self.game.recorded_winner_count;
+        self.user_global_bid.claimed_prize_count = 0;
+        self.game.winner_entries = self.game.winner_entries.checked_add(self.user_global_bid.draw_weight()).ok_or(WinError::NumericalOverflow)?;
+        self.game.recorded_winner_count = self.game.recorded_winner_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// lay out the draw entries of the next bidder, in the order the global bids were created,
+/// so every wallet owns one contiguous range of the winner draw
+#[derive(Accounts)]
+pub struct RegisterGameEntrant<'info> {
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.status == GameStatus::Drawing || game.status == GameStatus::Settled @ WinError::GameIsNotClosedStatus,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// global bid PDA of the next bidder
+    #[account(
+        mut,
+        seeds = [USER_BID.as_ref(), b"1".as_ref(), &user_global_bid.user_wallet.to_bytes(), &game.key().to_bytes()],
+        bump = user_global_bid.bump,
+        constraint = user_global_bid.entrant_index == game.registered_entrant_count @ WinError::InvalidAccount,
+    )]
+    pub user_global_bid: Box<Account<'info, UserBid>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> RegisterGameEntrant<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // sales are closed, so the weight is final; a refunded wallet takes no room
+        self.user_global_bid.entry_start_index = self.game.registered_entries;
+        self.game.registered_entries = self.game.registered_entries.checked_add(self.user_global_bid.draw_weight()).ok_or(WinError::NumericalOverflow)?;
+        self.game.registered_entrant_count = self.game.registered_entrant_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+}
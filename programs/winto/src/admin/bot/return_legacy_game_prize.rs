@@ -0,0 +1,115 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, TokenAccount, Token, Mint};
+
+/// bot hand out a prize NFT of a game in the old layout:
+/// to the winner it drew if the game reached its minimum cost, back to the organizer otherwise
+#[derive(Accounts)]
+pub struct ReturnLegacyGamePrize<'info> {
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// winner or organizer wallet
+    /// CHECK: verified in process
+    pub recipient_wallet: UncheckedAccount<'info>,
+
+    /// global bid PDA of the winner in the old layout; unused when the prize goes back to the organizer
+    /// CHECK: verified in process
+    pub recipient_global_bid: UncheckedAccount<'info>,
+
+    /// prize NFT
+    pub prize_nft: Box<Account<'info, Mint>>,
+
+    /// recipient nft ata
+    #[account(
+        mut,
+        constraint = recipient_nft_ata.mint == prize_nft.key() @ WinError::InvalidTokenMint,
+        constraint = recipient_nft_ata.owner == recipient_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub recipient_nft_ata: Box<Account<'info, TokenAccount>>,
+
+    /// NFT Pool
+    /// CHECK: Safe account
+    #[account(
+        seeds = [NFT_POOL.as_ref()],
+        bump
+    )]
+    pub nft_pool: UncheckedAccount<'info>,
+
+    /// NFT Pool ata of nft
+    #[account(
+        mut,
+        constraint = nft_pool_ata.mint == prize_nft.key() @ WinError::InvalidTokenMint,
+        constraint = nft_pool_ata.owner == nft_pool.key() @ WinError::InvalidTokenOwner
+    )]
+    pub nft_pool_ata: Box<Account<'info, TokenAccount>>,
+
+    /// game pda in the old layout
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub game: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ReturnLegacyGamePrize<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let mut game = LegacyGame::load(&self.game)?;
+        if !game.is_ended()? {
+            return Err(error!(WinError::GameIsNotClosedStatus));
+        }
+        let prize_slot = match game.nft_mint_address.iter().position(|mint| *mint == self.prize_nft.key()) {
+            Some(v) => v,
+            None => return Err(error!(WinError::InvalidTokenMint)),
+        };
+
+        // a prize the old program already handed out only needs its slot cleared
+        if self.nft_pool_ata.amount > 0 {
+            if game.is_sold()? {
+                // winners were drawn off-chain from the game's random numbers; the winner must have bid
+                let recipient_global_bid = LegacyUserBid::load(&self.recipient_global_bid, self.recipient_wallet.key(), self.game.key())?;
+                if recipient_global_bid.bid_number != 1 || recipient_global_bid.gbl_total_ticket_amount == 0 {
+                    return Err(error!(WinError::NotWinner));
+                }
+            } else if self.recipient_wallet.key() != game.nft_owner_wallet {
+                return Err(error!(WinError::AccessDenied));
+            }
+
+            // authority bump seed
+            let (_pda, bump_seed) = Pubkey::find_program_address(&[NFT_POOL.as_ref()], &crate::ID);
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.nft_pool_ata.to_account_info(),
+                        to: self.recipient_nft_ata.to_account_info(),
+                        authority: self.nft_pool.to_account_info(),
+                    },
+                    &[&[NFT_POOL.as_ref(), &[bump_seed]]],
+                ),
+                1,
+            )?;
+        }
+
+        game.nft_mint_address[prize_slot] = Pubkey::default();
+        if !game.is_sold()? && game.are_prizes_returned() {
+            game.is_nft_unstaked = true;
+        }
+        game.save(&self.game)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::globals::*;
+use anchor_lang::{prelude::*, solana_program::{keccak, sysvar}};
+
+/// bot reveals the draw secret of a closed game
+#[derive(Accounts)]
+pub struct RevealGameDraw<'info> {
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.total_entries > 0 @ WinError::NoTicketAmount,
+        constraint = game.prize_count > 0 @ WinError::NoGamePrize,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// SlotHashes sysvar
+    /// CHECK: Safe account
+    #[account(
+        address = sysvar::slot_hashes::ID @ WinError::InvalidSlotHashes
+    )]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> RevealGameDraw<'info> {
+    pub fn process(&mut self, args: RevealGameDrawArgs) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status == GameStatus::Settled {
+            return Err(error!(WinError::AlreadyDrawn));
+        }
+        if self.game.status != GameStatus::Drawing {
+            return Err(error!(WinError::GameIsNotDrawingStatus));
+        }
+        if keccak::hash(&args.draw_secret).0 != self.game.draw_commitment {
+            return Err(error!(WinError::InvalidDrawSecret));
+        }
+
+        // the slot was fixed when sales closed and its hash was unknown to the bot at commitment,
+        // so the reveal timing can not steer the seed
+        let (slot, slot_hash) = get_slot_hash_at(&self.slot_hashes, self.game.draw_slot)?;
+        let draw_seed = keccak::hashv(&[&args.draw_secret, &slot_hash, &self.game.key().to_bytes()]).0;
+
+        self.game.draw_secret = args.draw_secret;
+        self.game.draw_seed = draw_seed;
+        self.game.draw_slot = slot;
+        self.game.set_status(GameStatus::Settled)?;
+
+        // distinct winners can not outnumber the wallets holding tickets; every global bid holds at least one entry.
+        // past the winner cap the prizes are dealt round robin to the drawn winners
+        self.game.winner_count = match self.game.winner_mode {
+            WinnerMode::WinnerTakesAll => 1,
+            WinnerMode::OneNftPerWinner => std::cmp::min(std::cmp::min(self.game.prize_count, self.game.total_bidders), MAX_DRAW_WINNERS),
+        };
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct RevealGameDrawArgs {
+    /// draw secret committed at game creation
+    pub draw_secret: [u8;32],
+}
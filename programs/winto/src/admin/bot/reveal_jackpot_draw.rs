@@ -0,0 +1,81 @@
+use crate::globals::*;
+use anchor_lang::{prelude::*, solana_program::{clock, keccak, sysvar}};
+
+/// bot reveals the jackpot draw of an ended epoch and starts the next one
+#[derive(Accounts)]
+pub struct RevealJackpotDraw<'info> {
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// jackpot pda
+    #[account(
+        mut,
+        seeds = [JACKPOT.as_ref(), &jackpot.mint.to_bytes()],
+        bump = jackpot.bump,
+    )]
+    pub jackpot: Box<Account<'info, Jackpot>>,
+
+    /// SlotHashes sysvar
+    /// CHECK: Safe account
+    #[account(
+        address = sysvar::slot_hashes::ID @ WinError::InvalidSlotHashes
+    )]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> RevealJackpotDraw<'info> {
+    pub fn process(&mut self, args: RevealJackpotDrawArgs) -> Result<()> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        if current_time < self.jackpot.epoch_start.checked_add(self.win.jackpot_epoch_duration).ok_or(WinError::NumericalOverflow)? {
+            return Err(error!(WinError::JackpotEpochNotEnded));
+        }
+        if self.jackpot.draw_target_slot == 0 {
+            return Err(error!(WinError::JackpotEpochNotClosed));
+        }
+        if keccak::hash(&args.draw_secret).0 != self.jackpot.draw_commitment {
+            return Err(error!(WinError::InvalidDrawSecret));
+        }
+
+        // an unclaimed payout of the previous draw rolls over
+        self.jackpot.balance = self.jackpot.balance.checked_add(self.jackpot.pending_payout).ok_or(WinError::NumericalOverflow)?;
+        self.jackpot.pending_payout = 0;
+
+        // an epoch without entries rolls its balance over too
+        if self.jackpot.entry_count > 0 && self.jackpot.balance > 0 {
+            let (_slot, slot_hash) = get_slot_hash_at(&self.slot_hashes, self.jackpot.draw_target_slot)?;
+            let draw_seed = keccak::hashv(&[&args.draw_secret, &slot_hash, &self.jackpot.key().to_bytes(), &self.jackpot.epoch.to_le_bytes()]).0;
+
+            self.jackpot.winning_entry = draw_ticket_index(&draw_seed, 0, self.jackpot.entry_count);
+            self.jackpot.payout_epoch = self.jackpot.epoch;
+            self.jackpot.pending_payout = self.jackpot.balance;
+            self.jackpot.balance = 0;
+        }
+
+        self.jackpot.epoch = self.jackpot.epoch.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.jackpot.epoch_start = current_time;
+        self.jackpot.entry_count = 0;
+        self.jackpot.draw_target_slot = 0;
+        self.jackpot.draw_commitment = args.next_draw_commitment;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct RevealJackpotDrawArgs {
+    /// draw secret committed for the ended epoch
+    pub draw_secret: [u8;32],
+
+    /// bot draw commitment of the next epoch
+    pub next_draw_commitment: [u8;32],
+}
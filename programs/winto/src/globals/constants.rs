@@ -18,6 +18,14 @@ pub const GAME: &[u8] = b"game";
 
 pub const GAME_POOL: &[u8] = b"game:pool";
 
+pub const GAME_PRIZE: &[u8] = b"game:prize";
+
+pub const JACKPOT: &[u8] = b"jackpot";
+
+pub const JACKPOT_ENTRY: &[u8] = b"jackpot:entry";
+
+pub const PRIZE_COLLECTION: &[u8] = b"prize:collection";
+
 pub const WINGS_NFT_POOL: &[u8] = b"wings:pool";
 
 pub const USER_BID: &[u8] = b"bid";
@@ -28,4 +36,40 @@ pub const MERKLE_WHITELIST: &[u8] = b"win-whitelist";
 
 pub const MERKLE_WHITELIST_USER_PROOF: &[u8] = b"win-whitelist-user";
 
-pub const MERKLE_WHITELIST_HOLDER_PROOF: &[u8] = b"win-whitelist-holder";
\ No newline at end of file
+pub const MERKLE_WHITELIST_HOLDER_PROOF: &[u8] = b"win-whitelist-holder";
+
+/// seconds a game start time may lag behind the clock when the transaction lands
+pub const GAME_START_TIME_TOLERANCE: u32 = 60;
+
+/// max seconds a game can be scheduled ahead
+pub const MAX_GAME_START_DELAY: u32 = 30 * 86400;
+
+/// max total seconds anti-snipe can extend a game
+pub const MAX_ANTI_SNIPE_EXTENSION: u32 = 86400;
+
+/// max bundle tiers per game
+pub const MAX_BUNDLE_TIERS: usize = 3;
+
+/// one entry per WingsType
+pub const WINGS_TIER_COUNT: usize = 4;
+
+/// max bundle discount in basis points
+pub const MAX_BUNDLE_DISCOUNT_BPS: u16 = 5000;
+
+/// max jackpot slice in basis points
+pub const MAX_JACKPOT_FEE_BPS: u16 = 1000;
+
+/// slots between closing sales and the slot whose hash seeds the draw
+pub const DRAW_SLOT_DELAY: u64 = 10;
+
+/// slots the SlotHashes sysvar keeps; an older draw slot has aged out and is fixed again
+pub const SLOT_HASHES_WINDOW: u64 = 512;
+
+/// seconds the bot has to reveal a draw before the game is cancelled
+pub const DRAW_REVEAL_TIMEOUT: u32 = 3 * 86400;
+
+/// max distinct winners of a game; recording a winner reads every earlier winner's global bid
+pub const MAX_DRAW_WINNERS: u32 = 16;
+
+/// seconds after the extension grace period bidders have to close their bids before the organizer may close the game anyway
+pub const BID_CLOSE_PERIOD: u32 = 30 * 86400;
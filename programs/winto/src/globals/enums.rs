@@ -40,4 +40,39 @@ pub enum WingsType {
     Gold = 1,
     Silver = 2,
     Bronze = 3,
-}
\ No newline at end of file
+}
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum WinnerMode {
+    WinnerTakesAll = 0,
+    OneNftPerWinner = 1,
+}
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum GameStatus {
+    Scheduled = 0,
+    Open = 1,
+    Drawing = 2,
+    Settled = 3,
+    Cancelled = 4,
+    Recreated = 5,
+}
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum PricingMode {
+    Fixed = 0,
+    Linear = 1,
+    Step = 2,
+    Dutch = 3,
+}
+
+#[repr(u8)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum PrizeType {
+    Nft = 0,
+    Token = 1,
+    Sol = 2,
+}
@@ -97,4 +97,109 @@ pub enum WinError {
 
     #[msg("Invalid bonus ticket amount")]
     InvalidBonusTicketAmount,
+
+    #[msg("Game draw has been revealed already")]
+    AlreadyDrawn,
+
+    #[msg("Draw secret does not match the commitment")]
+    InvalidDrawSecret,
+
+    #[msg("Invalid slot hashes sysvar")]
+    InvalidSlotHashes,
+
+    #[msg("Game draw has not been revealed yet")]
+    NotDrawnYet,
+
+    #[msg("Game winner has been recorded already")]
+    WinnerAlreadyRecorded,
+
+    #[msg("You are not the winner of this prize")]
+    NotWinner,
+
+    #[msg("The bid does not cover the winning ticket")]
+    NotWinningBid,
+
+    #[msg("Invalid game status transition")]
+    InvalidGameStatus,
+
+    #[msg("Game is not drawing status")]
+    GameIsNotDrawingStatus,
+
+    #[msg("Game has tickets sold already")]
+    GameHasTickets,
+
+    #[msg("Game has reached minimum cost already")]
+    GameIsFunded,
+
+    #[msg("Game extension limit exceeded")]
+    ExtensionLimitExceeded,
+
+    #[msg("Game ticket supply limit exceeded")]
+    TicketLimitExceeded,
+
+    #[msg("Wallet ticket limit exceeded")]
+    WalletTicketLimitExceeded,
+
+    #[msg("Game start time is out of bounds")]
+    InvalidStartTime,
+
+    #[msg("Game has not started yet")]
+    GameIsNotStarted,
+
+    #[msg("Account is still in use")]
+    AccountInUse,
+
+    #[msg("Bid can not be rolled into this game")]
+    BidNotRollable,
+
+    #[msg("Invalid bundle tier")]
+    InvalidBundleTier,
+
+    #[msg("Game has no prize")]
+    NoGamePrize,
+
+    #[msg("Invalid prize type")]
+    InvalidPrizeType,
+
+    #[msg("Jackpot epoch has not ended yet")]
+    JackpotEpochNotEnded,
+
+    #[msg("Jackpot epoch has ended")]
+    JackpotEpochEnded,
+
+    #[msg("No jackpot payout to claim")]
+    NoJackpotPayout,
+
+    #[msg("Prize is not an NFT")]
+    InvalidPrizeNft,
+
+    #[msg("Prize collection is not allowed")]
+    PrizeCollectionNotAllowed,
+
+    #[msg("Wings NFT is not locked by organizer")]
+    WingsNftNotLocked,
+
+    #[msg("Wings NFT tier is not registered")]
+    UnknownWingsTier,
+
+    #[msg("Wings NFT is used by an active game")]
+    WingsNftInUse,
+
+    #[msg("Wings tier limit exceeded")]
+    TierLimitExceeded,
+
+    #[msg("Draw slot is not reached yet")]
+    DrawSlotNotReached,
+
+    #[msg("Draw slot is no longer in SlotHashes")]
+    DrawSlotExpired,
+
+    #[msg("Jackpot epoch is not closed")]
+    JackpotEpochNotClosed,
+
+    #[msg("Account is already migrated")]
+    AlreadyMigrated,
+
+    #[msg("Bidders are not laid out for the winner draw yet")]
+    EntrantsNotRegistered,
 }
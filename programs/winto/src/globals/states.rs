@@ -1,7 +1,10 @@
-use anchor_lang::{prelude::*, solana_program::clock};
+use anchor_lang::{prelude::*, solana_program::clock, Discriminator};
 use crate::constants::*;
+use crate::errors::*;
 use crate::utility::*;
 use crate::enums::*;
+use spl_token_metadata::state::Creator;
+use std::convert::TryInto;
 
 /// Win details
 #[account]
@@ -33,7 +36,7 @@ pub struct Win {
     /// game bonus tickets amount
     pub game_bonus_ticket_amount: u32,
 
-    /// community fee
+    /// community fee; base of the default Wings tier fee schedule
     pub community_fee: u16,
 
     /// freely NFT creator
@@ -56,6 +59,94 @@ pub struct Win {
 
     /// wings nft holder whitelist
     pub holder_whitelist_merkle_root: [u8; 32],
+
+    /// max total seconds an under-funded game can be extended
+    pub max_game_extension: u32,
+
+    /// seconds after the end time an expired under-funded game can still be extended
+    pub game_extension_grace_period: u32,
+
+    /// seconds bidders can opt out with a refund after a game is extended
+    pub extension_refund_window: u32,
+
+    /// jackpot slice of every settled game in basis points
+    pub jackpot_fee_bps: u16,
+
+    /// jackpot epoch duration
+    pub jackpot_epoch_duration: u32,
+
+    /// prize NFTs must belong to an allowlisted collection
+    pub require_prize_collection: bool,
+
+    /// Wings tier registry indexed by WingsType
+    pub wings_tiers: [WingsTierConfig; WINGS_TIER_COUNT],
+}
+
+impl Win {
+    /// Resolves the tier of a Wings NFT from its verified creators
+    pub fn wings_tier_of(&self, creators: &[Creator]) -> Option<WingsType> {
+        let tiers = [WingsType::Gold, WingsType::Silver, WingsType::Bronze];
+        for wings_type in tiers.iter() {
+            let tier_creator = self.wings_tiers[wings_type.clone() as usize].creator;
+            if tier_creator == Pubkey::default() {
+                continue;
+            }
+            if creators.iter().any(|creator| creator.verified && creator.address == tier_creator) {
+                return Some(wings_type.clone());
+            }
+        }
+        None
+    }
+
+    /// Fee schedule and perks of a Wings tier
+    pub fn wings_tier(&self, wings_type: &WingsType) -> WingsTierConfig {
+        self.wings_tiers[wings_type.clone() as usize]
+    }
+
+    /// Seeds the tier fees from the community fee the way games were charged before the tier table
+    pub fn seed_wings_tier_fees(&mut self) -> Result<()> {
+        // indexed by WingsType: None, Gold, Silver, Bronze
+        let multipliers: [u16; WINGS_TIER_COUNT] = [10, 7, 8, 9];
+        for (tier, multiplier) in self.wings_tiers.iter_mut().zip(multipliers.iter()) {
+            tier.fee_bps = self.community_fee.checked_mul(*multiplier).ok_or(WinError::NumericalOverflow)?;
+            if tier.fee_bps > 10000 {
+                return Err(error!(WinError::InvalidArgs));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Wings tier config; 0 limits are unlimited
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct WingsTierConfig {
+    /// verified creator of the tier; unused for WingsType::None
+    pub creator: Pubkey,
+
+    /// community fee in basis points
+    pub fee_bps: u16,
+
+    /// max prizes per game
+    pub max_prizes: u32,
+
+    /// max unfinished games per organizer
+    pub max_concurrent_games: u32,
+
+    /// max game duration
+    pub max_duration: u32,
+}
+
+impl WingsTierConfig {
+    /// Checks a game duration, extensions included, against the tier cap
+    pub fn verify_duration(
+        &self,
+        duration: u32,
+    ) -> Result<()> {
+        if self.max_duration > 0 && duration > self.max_duration {
+            return Err(error!(WinError::TierLimitExceeded));
+        }
+        Ok(())
+    }
 }
 
 /// User details
@@ -102,6 +193,12 @@ pub struct UserDetails {
 
     /// freely NFT mint address
     pub freely_nft_mint_address: Pubkey,
+
+    /// created games not finished yet
+    pub active_game_count: u32,
+
+    /// lowest nonce a new game may use; a closed game PDA is never created again
+    pub next_game_nonce: u32,
 }
 
 impl UserDetails {
@@ -110,24 +207,65 @@ impl UserDetails {
         proof: Vec<[u8; 32]>,
         organizer_wallet: Pubkey,
         root: [u8; 32],
-        wings_type: WingsType
+        wings_nft_mint: Pubkey
     ) -> bool {
         let node = anchor_lang::solana_program::keccak::hashv(&[
             &MERKLE_WHITELIST_USER_PROOF.as_ref(),
             &organizer_wallet.to_bytes(),
         ]);
-        let verify = match wings_type {
-            WingsType::None => {
-                merkle_tree_verify(proof, root, node.0)
-            }
-            _ => {
-                true
-            }
-        };
-        verify
+        // Wings holders are verified against their locked Wings NFT instead
+        if wings_nft_mint == Pubkey::default() {
+            merkle_tree_verify(proof, root, node.0)
+        } else {
+            true
+        }
+    }
+
+    /// Counts a new game against the organizer's tier limits
+    pub fn open_game(
+        &mut self,
+        tier: &WingsTierConfig,
+        duration: u32,
+    ) -> Result<()> {
+        tier.verify_duration(duration)?;
+        if tier.max_concurrent_games > 0 && self.active_game_count >= tier.max_concurrent_games {
+            return Err(error!(WinError::TierLimitExceeded));
+        }
+        self.active_game_count = self.active_game_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+
+    /// Takes a game nonce; nonces only grow so stale bids never match a new game
+    pub fn use_game_nonce(
+        &mut self,
+        game_nonce: u32,
+    ) -> Result<()> {
+        if game_nonce < self.next_game_nonce {
+            return Err(error!(WinError::InvalidArgs));
+        }
+        self.next_game_nonce = game_nonce.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+
+    /// Stops counting a finished game against the organizer's tier limits
+    pub fn release_game(
+        &mut self,
+    ) -> Result<()> {
+        self.active_game_count = self.active_game_count.checked_sub(1).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
     }
 }
 
+/// Ticket bundle tier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BundleTier {
+    /// min tickets in one purchase; 0 - unused tier
+    pub min_tickets: u32,
+
+    /// discount in basis points
+    pub discount_bps: u16,
+}
+
 /// Game PDA
 #[account]
 pub struct Game {
@@ -173,26 +311,493 @@ pub struct Game {
     /// Wings NFT mint address
     pub wings_nft_mint_address: Pubkey,
 
-    /// winner NFT prize count
-    pub winner_nft_count: [u8;5],
+    /// bot commitment for the draw; keccak hash of the draw secret
+    pub draw_commitment: [u8;32],
 
-    /// winner random number
-    pub winner_random_number: [u32;6],
+    /// draw secret revealed by bot
+    pub draw_secret: [u8;32],
 
-    /// nft mint address
-    pub nft_mint_address: [Pubkey;5]
+    /// draw seed; keccak hash of draw secret, slot hash and game key
+    pub draw_seed: [u8;32],
+
+    /// slot of the slot hash mixed into the draw seed; the target is fixed when sales close
+    pub draw_slot: u64,
+
+    /// winner mode; 0 - one winner takes all NFTs, 1 - one NFT per winner
+    pub winner_mode: WinnerMode,
+
+    /// prize NFT count
+    pub prize_count: u32,
+
+    /// winner count, fixed when the draw is revealed
+    pub winner_count: u32,
+
+    /// recorded winner count
+    pub recorded_winner_count: u32,
+
+    /// wallets holding at least one ticket
+    pub total_bidders: u32,
+
+    /// draw entries; bought, bonus and freely tickets share one ticket sequence
+    pub total_entries: u32,
+
+    /// game status
+    pub status: GameStatus,
+
+    /// total extended seconds
+    pub extended_duration: u32,
+
+    /// bidders can opt out with a refund until this time
+    pub refund_window_end: u32,
+
+    /// max draw entries, bonus and freely tickets included; 0 - unlimited
+    pub max_tickets: u32,
+
+    /// max draw entries per wallet; 0 - unlimited
+    pub max_tickets_per_wallet: u32,
+
+    /// anti-snipe window; bids in the last seconds push the end time out, 0 - disabled
+    pub anti_snipe_window: u32,
+
+    /// anti-snipe extension seconds per late bid
+    pub anti_snipe_extension: u32,
+
+    /// max total anti-snipe extension seconds
+    pub anti_snipe_max_extension: u32,
+
+    /// total anti-snipe extended seconds
+    pub anti_snipe_extended: u32,
+
+    /// open user bid PDA count; the game PDA can be closed once it drops to 0
+    pub bid_account_count: u32,
+
+    /// cancelled game this game was recreated from
+    pub previous_game: Pubkey,
+
+    /// pricing mode
+    pub pricing_mode: PricingMode,
+
+    /// price increase per ticket (linear) or per step (step)
+    pub price_increment: u64,
+
+    /// tickets per price step
+    pub price_step_size: u32,
+
+    /// dutch floor price reached at game end
+    pub floor_price: u64,
+
+    /// total ticket money actually paid
+    pub total_paid: u64,
+
+    /// volume discount tiers
+    pub bundle_tiers: [BundleTier; MAX_BUNDLE_TIERS],
+
+    /// game holding the prize PDAs; itself unless recreated
+    pub prize_game: Pubkey,
+
+    /// prizes claimed by winners or returned to organizer
+    pub claimed_prize_count: u32,
+
+    /// finished game no longer counts on the organizer's game limit and Wings NFT lock
+    pub is_released: bool,
+
+    /// community fee in basis points at creation
+    pub fee_bps: u16,
+
+    /// jackpot fee in basis points at creation
+    pub jackpot_fee_bps: u16,
+
+    /// community fee recipient at creation
+    pub fee_recipient: Pubkey,
+
+    /// time sales closed; the draw times out from here
+    pub drawing_started_at: u32,
+
+    /// cancelled for good by a draw timeout; never reopened by an extension
+    pub is_cancel_final: bool,
+
+    /// claimed prize PDAs of a settled game closed by the organizer
+    pub closed_prize_count: u32,
+
+    /// global bids created; their order fixes the wallet layout of the winner draw
+    pub entrant_count: u32,
+
+    /// global bids laid out for the winner draw, in entrant order
+    pub registered_entrant_count: u32,
+
+    /// draw entries of the laid out wallets still in the game
+    pub registered_entries: u32,
+
+    /// draw entries of recorded winners, left out of later winner draws
+    pub winner_entries: u32,
 }
 
 impl Game {
+    /// payment mint; default for SOL games
+    pub fn payment_mint(
+        &self,
+    ) -> Pubkey {
+        match self.coin_type {
+            CoinType::SOL => Pubkey::default(),
+            CoinType::TOKEN => self.ticket_token_address,
+        }
+    }
+
+    /// a token game needs a ticket mint
+    pub fn verify_payment(
+        &self,
+    ) -> Result<()> {
+        if self.coin_type == CoinType::TOKEN && self.ticket_token_address == Pubkey::default() {
+            return Err(error!(WinError::InvalidArgs));
+        }
+        Ok(())
+    }
+
+    /// winner slot of a prize; the prize with the same index as the slot holds its draw state
+    pub fn prize_winner_slot(
+        &self,
+        prize_index: u32,
+    ) -> u32 {
+        match self.winner_mode {
+            WinnerMode::WinnerTakesAll => 0,
+            WinnerMode::OneNftPerWinner => prize_index % self.winner_count,
+        }
+    }
+
+    /// prize count of a winner slot
+    pub fn winner_prize_count(
+        &self,
+        winner_slot: u32,
+    ) -> u32 {
+        match self.winner_mode {
+            WinnerMode::WinnerTakesAll => self.prize_count,
+            WinnerMode::OneNftPerWinner => self.prize_count / self.winner_count + (winner_slot < self.prize_count % self.winner_count) as u32,
+        }
+    }
+
+    /// winning entry of a winner slot among the entries of wallets that have not won yet
+    pub fn draw_winner_entry(
+        &self,
+        winner_slot: u32,
+    ) -> Result<u32> {
+        let eligible_entries = self.registered_entries.checked_sub(self.winner_entries).ok_or(WinError::NumericalOverflow)?;
+        if eligible_entries == 0 {
+            return Err(error!(WinError::NoTicketAmount));
+        }
+        Ok(draw_ticket_index(&self.draw_seed, winner_slot, eligible_entries))
+    }
+
+    /// paid ticket money
+    pub fn total_earn(
+        &self,
+    ) -> Result<u64> {
+        Ok(self.total_paid)
+    }
+
+    /// dutch ticket price now; decays linearly from ticket price to floor price over the duration
+    pub fn dutch_price(
+        &self,
+    ) -> Result<u64> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        self.dutch_price_at(current_time)
+    }
+
+    /// dutch price at a time; decays over the original duration so an extension keeps the floor
+    pub fn dutch_price_at(
+        &self,
+        current_time: u32,
+    ) -> Result<u64> {
+        let decay_duration = self.duration.checked_sub(self.extended_duration).ok_or(WinError::NumericalOverflow)?;
+        let elapsed = std::cmp::min(current_time.saturating_sub(self.opened_timestamp), decay_duration);
+        let decay = (self.ticket_price.checked_sub(self.floor_price).ok_or(WinError::NumericalOverflow)? as u128)
+            .checked_mul(elapsed as u128).ok_or(WinError::NumericalOverflow)?
+            .checked_div(decay_duration as u128).ok_or(WinError::NumericalOverflow)?;
+        Ok(self.ticket_price.checked_sub(decay as u64).ok_or(WinError::NumericalOverflow)?)
+    }
+
+    /// exact cost of the next tickets
+    pub fn ticket_cost(
+        &self,
+        ticket_amount: u32,
+    ) -> Result<u64> {
+        let amount = ticket_amount as u128;
+        let sold = self.current_total_tickets as u128;
+        let base_cost = amount.checked_mul(self.ticket_price as u128).ok_or(WinError::NumericalOverflow)?;
+        let cost = match self.pricing_mode {
+            PricingMode::Fixed => base_cost,
+            PricingMode::Linear => {
+                // increments of tickets sold..sold+amount
+                let steps = amount
+                    .checked_mul(sold).ok_or(WinError::NumericalOverflow)?
+                    .checked_add(amount.checked_mul(amount.saturating_sub(1)).ok_or(WinError::NumericalOverflow)? / 2).ok_or(WinError::NumericalOverflow)?;
+                base_cost.checked_add(steps.checked_mul(self.price_increment as u128).ok_or(WinError::NumericalOverflow)?).ok_or(WinError::NumericalOverflow)?
+            }
+            PricingMode::Step => {
+                let step_size = self.price_step_size as u128;
+                let steps = step_index_sum(sold.checked_add(amount).ok_or(WinError::NumericalOverflow)?, step_size)?
+                    .checked_sub(step_index_sum(sold, step_size)?).ok_or(WinError::NumericalOverflow)?;
+                base_cost.checked_add(steps.checked_mul(self.price_increment as u128).ok_or(WinError::NumericalOverflow)?).ok_or(WinError::NumericalOverflow)?
+            }
+            PricingMode::Dutch => {
+                amount.checked_mul(self.dutch_price()? as u128).ok_or(WinError::NumericalOverflow)?
+            }
+        };
+        Ok(cost.try_into().map_err(|_| WinError::NumericalOverflow)?)
+    }
+
+    /// best bundle discount for a purchase
+    pub fn bundle_discount_bps(
+        &self,
+        ticket_amount: u32,
+    ) -> u16 {
+        self.bundle_tiers.iter()
+            .filter(|tier| tier.min_tickets > 0 && ticket_amount >= tier.min_tickets)
+            .map(|tier| tier.discount_bps)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// exact cost of a purchase after bundle discount
+    pub fn purchase_cost(
+        &self,
+        ticket_amount: u32,
+    ) -> Result<u64> {
+        let cost = self.ticket_cost(ticket_amount)? as u128;
+        let discount = cost
+            .checked_mul(self.bundle_discount_bps(ticket_amount) as u128).ok_or(WinError::NumericalOverflow)?
+            .checked_div(10000).ok_or(WinError::NumericalOverflow)?;
+        Ok(cost.checked_sub(discount).ok_or(WinError::NumericalOverflow)? as u64)
+    }
+
+    /// checks pricing mode parameters and that the game can reach its minimum cost
+    pub fn verify_pricing(
+        &self,
+    ) -> Result<()> {
+        let lowest_price = match self.pricing_mode {
+            PricingMode::Step => {
+                if self.price_step_size == 0 {
+                    return Err(error!(WinError::InvalidArgs));
+                }
+                self.ticket_price
+            }
+            PricingMode::Dutch => {
+                if self.floor_price == 0 || self.floor_price > self.ticket_price {
+                    return Err(error!(WinError::WrongVaule));
+                }
+                self.floor_price
+            }
+            _ => self.ticket_price,
+        };
+        for tier in self.bundle_tiers.iter() {
+            if tier.discount_bps > MAX_BUNDLE_DISCOUNT_BPS || (tier.discount_bps > 0 && tier.min_tickets < 2) {
+                return Err(error!(WinError::InvalidBundleTier));
+            }
+        }
+        // deepest discount still has to reach the minimum cost
+        let max_discount_bps = self.bundle_tiers.iter().map(|tier| tier.discount_bps).max().unwrap_or(0);
+        let lowest_price = (lowest_price as u128)
+            .checked_mul(10000u128.checked_sub(max_discount_bps as u128).ok_or(WinError::NumericalOverflow)?).ok_or(WinError::NumericalOverflow)?
+            .checked_div(10000).ok_or(WinError::NumericalOverflow)? as u64;
+        verify_game_economics(lowest_price, self.minimum_cost, self.max_tickets)
+    }
+
+    /// end time including anti-snipe extension
+    pub fn end_time(
+        &self,
+    ) -> Result<u32> {
+        Ok(self.opened_timestamp
+            .checked_add(self.duration).ok_or(WinError::NumericalOverflow)?
+            .checked_add(self.anti_snipe_extended).ok_or(WinError::NumericalOverflow)?)
+    }
+
     pub fn verify_game_time(
         &self,
-    ) -> bool {
+    ) -> Result<bool> {
         let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
-        if current_time >= self.opened_timestamp.checked_add(self.duration).unwrap() {
-            false
-        } else {
-            true
+        Ok(current_time < self.end_time()?)
+    }
+
+    /// reject a schedule whose latest possible end time overflows
+    pub fn verify_time_bounds(
+        &self,
+    ) -> Result<()> {
+        self.opened_timestamp
+            .checked_add(self.duration)
+            .and_then(|end_time| end_time.checked_add(self.anti_snipe_max_extension))
+            .ok_or(WinError::InvalidArgs)?;
+        Ok(())
+    }
+
+    /// check ticket supply and per wallet caps for a purchase; the caps bound draw entries,
+    /// so bonus and freely tickets count against them like bought ones
+    pub fn verify_ticket_limits(
+        &self,
+        wallet_entries: u32,
+        ticket_amount: u32,
+        bonus_ticket_amount: u32,
+    ) -> Result<()> {
+        let entry_amount = ticket_amount.checked_add(bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?;
+        if self.max_tickets > 0 && self.total_entries.checked_add(entry_amount).ok_or(WinError::NumericalOverflow)? > self.max_tickets {
+            return Err(error!(WinError::TicketLimitExceeded));
+        }
+        if self.max_tickets_per_wallet > 0 && wallet_entries.checked_add(entry_amount).ok_or(WinError::NumericalOverflow)? > self.max_tickets_per_wallet {
+            return Err(error!(WinError::WalletTicketLimitExceeded));
         }
+        Ok(())
+    }
+
+    /// push the end time out for a bid landing inside the anti-snipe window, up to the max extension
+    pub fn apply_anti_snipe(
+        &mut self,
+    ) -> Result<()> {
+        if self.anti_snipe_window == 0 || self.anti_snipe_extension == 0 {
+            return Ok(());
+        }
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        let end_time = self.end_time()?;
+        if current_time < end_time && end_time - current_time <= self.anti_snipe_window {
+            let remaining = self.anti_snipe_max_extension.saturating_sub(self.anti_snipe_extended);
+            let extension = std::cmp::min(self.anti_snipe_extension, remaining);
+            self.anti_snipe_extended = self.anti_snipe_extended.checked_add(extension).ok_or(WinError::NumericalOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// true once an organizer extension is no longer possible
+    pub fn is_past_extension_grace(
+        &self,
+        grace_period: u32,
+    ) -> Result<bool> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        Ok(current_time >= self.end_time()?.checked_add(grace_period).ok_or(WinError::NumericalOverflow)?)
+    }
+
+    /// bidders had the bid close period to close their bids; an abandoned bid no longer keeps the game open
+    pub fn is_past_bid_close_period(
+        &self,
+        grace_period: u32,
+    ) -> Result<bool> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        let close_time = self.end_time()?
+            .checked_add(grace_period).ok_or(WinError::NumericalOverflow)?
+            .checked_add(BID_CLOSE_PERIOD).ok_or(WinError::NumericalOverflow)?;
+        Ok(current_time >= close_time)
+    }
+
+    /// fix the fee terms settlement runs against; needs the Wings tier resolved
+    pub fn snapshot_fee_terms(
+        &mut self,
+        win: &Win,
+    ) -> Result<()> {
+        let fee_bps = win.wings_tier(&self.wings_type).fee_bps;
+        if fee_bps.checked_add(win.jackpot_fee_bps).ok_or(WinError::NumericalOverflow)? > 10000 {
+            return Err(error!(WinError::InvalidArgs));
+        }
+        let (fee_recipient, _bump) = Pubkey::find_program_address(&[COMMUNITY.as_ref()], &crate::ID);
+        self.fee_bps = fee_bps;
+        self.jackpot_fee_bps = win.jackpot_fee_bps;
+        self.fee_recipient = fee_recipient;
+        Ok(())
+    }
+
+    /// reject prizes beyond the tier limit
+    pub fn verify_prize_limit(
+        &self,
+        max_prizes: u32,
+        added_prizes: u32,
+    ) -> Result<()> {
+        let prize_count = self.prize_count.checked_add(added_prizes).ok_or(WinError::NumericalOverflow)?;
+        if max_prizes > 0 && prize_count > max_prizes {
+            return Err(error!(WinError::TierLimitExceeded));
+        }
+        Ok(())
+    }
+
+    /// true once tickets can no longer be drawn or reopened
+    pub fn are_bids_final(
+        &self,
+        grace_period: u32,
+    ) -> Result<bool> {
+        Ok(match self.status {
+            GameStatus::Recreated => true,
+            GameStatus::Cancelled => self.is_cancel_final || self.is_past_extension_grace(grace_period)?,
+            GameStatus::Settled => self.recorded_winner_count == self.winner_count,
+            _ => false,
+        })
+    }
+
+    /// close sales once the ticket supply cap is reached
+    pub fn close_if_sold_out(
+        &mut self,
+    ) -> Result<()> {
+        if self.max_tickets > 0 && self.total_entries >= self.max_tickets {
+            self.start_drawing()?;
+        }
+        Ok(())
+    }
+
+    /// close sales and fix the future slot whose hash seeds the draw
+    pub fn start_drawing(
+        &mut self,
+    ) -> Result<()> {
+        self.set_status(GameStatus::Drawing)?;
+        let clock = clock::Clock::get().unwrap();
+        self.draw_slot = clock.slot.checked_add(DRAW_SLOT_DELAY).ok_or(WinError::NumericalOverflow)?;
+        self.drawing_started_at = clock.unix_timestamp as u32;
+        Ok(())
+    }
+
+    /// checked status transition
+    pub fn set_status(
+        &mut self,
+        status: GameStatus,
+    ) -> Result<()> {
+        let allowed = match (&self.status, &status) {
+            (GameStatus::Scheduled, GameStatus::Open) => true,
+            (GameStatus::Scheduled, GameStatus::Cancelled) => true,
+            (GameStatus::Open, GameStatus::Drawing) => true,
+            (GameStatus::Open, GameStatus::Cancelled) => true,
+            (GameStatus::Drawing, GameStatus::Settled) => true,
+            (GameStatus::Drawing, GameStatus::Cancelled) => true,
+            (GameStatus::Cancelled, GameStatus::Recreated) => true,
+            (GameStatus::Cancelled, GameStatus::Open) => true,
+            _ => false,
+        };
+        if !allowed {
+            return Err(error!(WinError::InvalidGameStatus));
+        }
+        self.status = status;
+        Ok(())
+    }
+
+    /// apply time based transitions; a scheduled game opens at its start time,
+    /// an open game closes at its end time into drawing or cancelled by its minimum cost,
+    /// a draw slot that aged out is fixed again and a draw the bot never reveals is
+    /// cancelled after the reveal timeout
+    pub fn update_status(
+        &mut self,
+    ) -> Result<()> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        if self.status == GameStatus::Scheduled && current_time >= self.opened_timestamp {
+            self.set_status(GameStatus::Open)?;
+        }
+        if self.status == GameStatus::Open && !self.verify_game_time()? {
+            if self.total_earn()? >= self.minimum_cost {
+                self.start_drawing()?;
+            } else {
+                self.set_status(GameStatus::Cancelled)?;
+            }
+        }
+        if self.status == GameStatus::Drawing && current_time >= self.drawing_started_at.checked_add(DRAW_REVEAL_TIMEOUT).ok_or(WinError::NumericalOverflow)? {
+            self.set_status(GameStatus::Cancelled)?;
+            self.is_cancel_final = true;
+        }
+        // a missed reveal window fixes a new future slot; the reveal timeout keeps running
+        let current_slot = clock::Clock::get().unwrap().slot;
+        if self.status == GameStatus::Drawing && is_draw_slot_expired(self.draw_slot, current_slot) {
+            self.draw_slot = current_slot.checked_add(DRAW_SLOT_DELAY).ok_or(WinError::NumericalOverflow)?;
+        }
+        Ok(())
     }
 }
 
@@ -240,6 +845,178 @@ pub struct UserBid {
 
     /// bump
     pub bump: u8,
+
+    /// first ticket index of this bid in the game ticket sequence
+    pub ticket_start_index: u32,
+
+    /// drawn as a winner or not; set on global bid only
+    pub is_winner: bool,
+
+    /// paid amount of this bid
+    pub bought_amount: u64,
+
+    /// global paid amount
+    pub gbl_paid_amount: u64,
+
+    /// winner slot; set on global bid of a winner
+    pub winner_slot: u32,
+
+    /// claimed prize count; set on global bid of a winner
+    pub claimed_prize_count: u32,
+
+    /// global draw entries of the wallet, bonus and freely tickets included
+    pub gbl_entry_amount: u32,
+
+    /// order of the global bid among the bidders of the game
+    pub entrant_index: u32,
+
+    /// first entry of the wallet in the winner draw layout; set on global bid
+    pub entry_start_index: u32,
+}
+
+impl UserBid {
+    /// draw entries of this bid; bonus and freely tickets win like bought ones but are never refunded
+    pub fn ticket_count(
+        &self,
+    ) -> u32 {
+        self.bought_ticket_amount
+            .saturating_add(self.bonus_ticket_amount)
+            .saturating_add(self.freely_ticket_amount)
+    }
+
+    pub fn covers_ticket(
+        &self,
+        ticket_index: u32,
+    ) -> bool {
+        ticket_index >= self.ticket_start_index && ticket_index - self.ticket_start_index < self.ticket_count()
+    }
+
+    /// winner draw entries of a global bid; a refunded wallet has none
+    pub fn draw_weight(
+        &self,
+    ) -> u32 {
+        if self.funds_status == FundsStatus::NotClaimed {
+            self.gbl_entry_amount
+        } else {
+            0
+        }
+    }
+
+    /// whether the wallet range of a global bid covers an entry of the winner draw layout
+    pub fn covers_entry(
+        &self,
+        entry_index: u32,
+    ) -> bool {
+        entry_index >= self.entry_start_index && entry_index - self.entry_start_index < self.draw_weight()
+    }
+}
+
+/// Game prize PDA
+#[account]
+pub struct GamePrize {
+    /// game holding the prize
+    pub game: Pubkey,
+
+    /// prize index
+    pub index: u32,
+
+    /// prize mint address; default for SOL prizes
+    pub mint_address: Pubkey,
+
+    /// prize type
+    pub prize_type: PrizeType,
+
+    /// prize amount; 1 for NFT prizes, lamports for SOL prizes
+    pub amount: u64,
+
+    /// winner wallet; set on the prize holding the draw state of a winner slot
+    pub winner_wallet: Pubkey,
+
+    /// claimed by winner or returned to organizer
+    pub is_claimed: bool,
+
+    /// bump
+    pub bump: u8,
+}
+
+/// Prize collection allowlist PDA; a collection is identified by its verified creator
+#[account]
+pub struct PrizeCollection {
+    /// verified collection creator
+    pub creator: Pubkey,
+
+    /// bump
+    pub bump: u8,
+}
+
+/// Jackpot PDA per payment mint; funds stay in the game pool
+#[account]
+pub struct Jackpot {
+    /// payment mint; default for SOL
+    pub mint: Pubkey,
+
+    /// coin type
+    pub coin_type: CoinType,
+
+    /// jackpot balance
+    pub balance: u64,
+
+    /// current epoch
+    pub epoch: u32,
+
+    /// current epoch start time
+    pub epoch_start: u32,
+
+    /// entries of the current epoch
+    pub entry_count: u32,
+
+    /// bot commitment for the current epoch draw; keccak hash of the draw secret
+    pub draw_commitment: [u8;32],
+
+    /// epoch of the pending payout
+    pub payout_epoch: u32,
+
+    /// winning entry index of the pending payout
+    pub winning_entry: u32,
+
+    /// pending payout; rolls back into the balance if unclaimed by the next draw
+    pub pending_payout: u64,
+
+    /// bump
+    pub bump: u8,
+
+    /// slot whose hash seeds the epoch draw; 0 until the epoch is closed
+    pub draw_target_slot: u64,
+}
+
+impl Jackpot {
+    /// an entry of a drawn epoch can be closed unless it holds the unclaimed payout
+    pub fn is_entry_closable(
+        &self,
+        entry: &JackpotEntry,
+    ) -> bool {
+        let is_pending_winner = self.pending_payout > 0 && entry.epoch == self.payout_epoch && entry.index == self.winning_entry;
+        entry.epoch < self.epoch && !is_pending_winner
+    }
+}
+
+/// Jackpot entry PDA of a wallet in an epoch
+#[account]
+pub struct JackpotEntry {
+    /// user key
+    pub user_wallet: Pubkey,
+
+    /// jackpot pda key
+    pub jackpot: Pubkey,
+
+    /// epoch
+    pub epoch: u32,
+
+    /// entry index in the epoch
+    pub index: u32,
+
+    /// bump
+    pub bump: u8,
 }
 
 /// User Wings NFT locked PDA
@@ -250,4 +1027,346 @@ pub struct UserWingsNftLocked {
 
     /// wings nft mint address
     pub wings_nft_mint: Pubkey,
-}
\ No newline at end of file
+
+    /// tier resolved when the NFT was locked
+    pub wings_type: WingsType,
+
+    /// unsettled games created with this Wings NFT; unlocking needs 0
+    pub active_game_count: u32,
+}
+
+/// Game PDA in the layout before prizes moved to GamePrize PDAs.
+/// These games can no longer sell or draw; they are only wound down.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyGame {
+    /// nft owner wallet
+    pub nft_owner_wallet: Pubkey,
+
+    /// token address for buy ticket
+    pub ticket_token_address: Pubkey,
+
+    /// ticket price
+    pub ticket_price: u64,
+
+    /// minimum cost
+    pub minimum_cost: u64,
+
+    /// opened timestamp
+    pub opened_timestamp: u32,
+
+    /// duration
+    pub duration: u32,
+
+    /// total tickets
+    pub current_total_tickets: u32,
+
+    /// total bonus tickets
+    pub current_total_bonus_tickets: u32,
+
+    /// coin type; 0 - SOL, 1 - TOKEN
+    pub coin_type: CoinType,
+
+    /// nft stake or unstake; true: unstake, false: stake
+    pub is_nft_unstaked: bool,
+
+    /// fund status
+    pub funds_status: FundsStatus,
+
+    /// Wings NFT type
+    pub wings_type: WingsType,
+
+    /// bump
+    pub bump: u8,
+
+    /// Wings NFT mint address
+    pub wings_nft_mint_address: Pubkey,
+
+    /// winner NFT prize count
+    pub winner_nft_count: [u8;5],
+
+    /// winner random number
+    pub winner_random_number: [u32;6],
+
+    /// nft mint address; cleared once the NFT left the pool
+    pub nft_mint_address: [Pubkey;5]
+}
+
+impl LegacyGame {
+    /// Reads a Game PDA still in the old layout; games in the current layout are rejected
+    pub fn load(
+        info: &AccountInfo,
+    ) -> Result<Self> {
+        if *info.owner != crate::ID || info.data_len() != 8 + std::mem::size_of::<LegacyGame>() {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        let data = info.try_borrow_data()?;
+        if data[..8] != Game::discriminator() {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+
+    /// Writes the game back in the old layout
+    pub fn save(
+        &self,
+        info: &AccountInfo,
+    ) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        self.serialize(&mut &mut data[8..])?;
+        Ok(())
+    }
+
+    pub fn total_earn(
+        &self,
+    ) -> Result<u64> {
+        Ok((self.ticket_price as u128).checked_mul(self.current_total_tickets as u128).ok_or(WinError::NumericalOverflow)? as u64)
+    }
+
+    /// the game ran its duration; old games settle only then, as they did before
+    pub fn is_ended(
+        &self,
+    ) -> Result<bool> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        Ok(current_time > self.opened_timestamp.checked_add(self.duration).ok_or(WinError::NumericalOverflow)?)
+    }
+
+    /// every prize NFT left the pool
+    pub fn are_prizes_returned(
+        &self,
+    ) -> bool {
+        self.nft_mint_address.iter().all(|mint| *mint == Pubkey::default())
+    }
+
+    /// the game reached its minimum cost; otherwise it is cancelled and refunded
+    pub fn is_sold(
+        &self,
+    ) -> Result<bool> {
+        Ok(self.total_earn()? >= self.minimum_cost)
+    }
+}
+
+/// User Bid PDA in the layout before draw entries were tracked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyUserBid {
+    /// user key
+    pub user_wallet: Pubkey,
+
+    /// game pda key
+    pub game_pda_address: Pubkey,
+
+    /// bid create timestamp
+    pub opened_timestamp: u32,
+
+    /// total ticket amount
+    pub gbl_total_ticket_amount: u32,
+
+    /// real bought ticket amount
+    pub bought_ticket_amount: u32,
+
+    /// global bonus ticket amount
+    pub gbl_bonus_ticket_amount: u32,
+
+    /// bonus ticket amount
+    pub bonus_ticket_amount: u32,
+
+    /// freely ticket amount
+    pub freely_ticket_amount: u32,
+
+    /// total bid number
+    pub total_bid_number: u8,
+
+    /// bid number
+    pub bid_number: u8,
+
+    /// fund status
+    pub funds_status: FundsStatus,
+
+    /// winner claim nft
+    pub winner_nft_claim: bool,
+
+    /// bonus ticket amount distribute
+    pub gbl_has_distirbuted_bonus: bool,
+
+    /// bump
+    pub bump: u8,
+}
+
+impl LegacyUserBid {
+    /// Reads a bid PDA of a wallet in a game still in the old layout
+    pub fn load(
+        info: &AccountInfo,
+        user_wallet: Pubkey,
+        game: Pubkey,
+    ) -> Result<Self> {
+        if *info.owner != crate::ID || info.data_len() != 8 + std::mem::size_of::<LegacyUserBid>() {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        let bid = {
+            let data = info.try_borrow_data()?;
+            if data[..8] != UserBid::discriminator() {
+                return Err(error!(WinError::InvalidAccount));
+            }
+            Self::deserialize(&mut &data[8..])?
+        };
+        let (bid_pda, _bump) = Pubkey::find_program_address(
+            &[USER_BID.as_ref(), bid.bid_number.to_string().as_ref(), &user_wallet.to_bytes(), &game.to_bytes()],
+            &crate::ID,
+        );
+        if info.key() != bid_pda || bid.user_wallet != user_wallet || bid.game_pda_address != game {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        Ok(bid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zeroed_game() -> Game {
+        Game::deserialize(&mut &[0u8; 2048][..]).unwrap()
+    }
+
+    fn zeroed_user_bid() -> UserBid {
+        UserBid::deserialize(&mut &[0u8; 1024][..]).unwrap()
+    }
+
+    #[test]
+    fn seeded_tier_fees_match_legacy_schedule() {
+        let mut win = Win::deserialize(&mut &[0u8; 2048][..]).unwrap();
+        win.community_fee = 50;
+        win.seed_wings_tier_fees().unwrap();
+        assert_eq!(win.wings_tier(&WingsType::None).fee_bps, 500);
+        assert_eq!(win.wings_tier(&WingsType::Gold).fee_bps, 350);
+        assert_eq!(win.wings_tier(&WingsType::Silver).fee_bps, 400);
+        assert_eq!(win.wings_tier(&WingsType::Bronze).fee_bps, 450);
+        win.community_fee = 1001;
+        assert!(win.seed_wings_tier_fees().is_err());
+    }
+
+    #[test]
+    fn linear_cost_adds_increment_per_ticket_sold() {
+        let mut game = zeroed_game();
+        game.pricing_mode = PricingMode::Linear;
+        game.ticket_price = 100;
+        game.price_increment = 10;
+        assert_eq!(game.ticket_cost(3).unwrap(), 100 + 110 + 120);
+        game.current_total_tickets = 2;
+        assert_eq!(game.ticket_cost(2).unwrap(), 120 + 130);
+    }
+
+    #[test]
+    fn step_cost_adds_increment_per_step() {
+        let mut game = zeroed_game();
+        game.pricing_mode = PricingMode::Step;
+        game.ticket_price = 100;
+        game.price_increment = 10;
+        game.price_step_size = 2;
+        assert_eq!(game.ticket_cost(5).unwrap(), 100 + 100 + 110 + 110 + 120);
+        game.current_total_tickets = 1;
+        assert_eq!(game.ticket_cost(4).unwrap(), 100 + 110 + 110 + 120);
+    }
+
+    #[test]
+    fn dutch_price_decays_to_floor() {
+        let mut game = zeroed_game();
+        game.pricing_mode = PricingMode::Dutch;
+        game.ticket_price = 1000;
+        game.floor_price = 200;
+        game.opened_timestamp = 1000;
+        game.duration = 100;
+        assert_eq!(game.dutch_price_at(900).unwrap(), 1000);
+        assert_eq!(game.dutch_price_at(1000).unwrap(), 1000);
+        assert_eq!(game.dutch_price_at(1050).unwrap(), 600);
+        assert_eq!(game.dutch_price_at(1100).unwrap(), 200);
+        assert_eq!(game.dutch_price_at(1200).unwrap(), 200);
+    }
+
+    #[test]
+    fn dutch_price_stays_at_floor_after_extension() {
+        let mut game = zeroed_game();
+        game.pricing_mode = PricingMode::Dutch;
+        game.ticket_price = 1000;
+        game.floor_price = 200;
+        game.opened_timestamp = 1000;
+        game.duration = 150;
+        game.extended_duration = 50;
+        assert_eq!(game.dutch_price_at(1050).unwrap(), 600);
+        assert_eq!(game.dutch_price_at(1120).unwrap(), 200);
+    }
+
+    #[test]
+    fn bundle_discount_uses_deepest_reached_tier() {
+        let mut game = zeroed_game();
+        game.ticket_price = 100;
+        game.bundle_tiers[0] = BundleTier { min_tickets: 5, discount_bps: 1000 };
+        game.bundle_tiers[1] = BundleTier { min_tickets: 10, discount_bps: 2000 };
+        assert_eq!(game.purchase_cost(4).unwrap(), 400);
+        assert_eq!(game.purchase_cost(5).unwrap(), 450);
+        assert_eq!(game.purchase_cost(10).unwrap(), 800);
+    }
+
+    #[test]
+    fn bundle_discount_applies_to_linear_cost() {
+        let mut game = zeroed_game();
+        game.pricing_mode = PricingMode::Linear;
+        game.ticket_price = 100;
+        game.price_increment = 10;
+        game.bundle_tiers[0] = BundleTier { min_tickets: 3, discount_bps: 1000 };
+        assert_eq!(game.purchase_cost(3).unwrap(), 297);
+    }
+
+    #[test]
+    fn set_status_allows_only_known_transitions() {
+        let mut game = zeroed_game();
+        assert!(game.status == GameStatus::Scheduled);
+        assert!(game.set_status(GameStatus::Settled).is_err());
+        game.set_status(GameStatus::Open).unwrap();
+        game.set_status(GameStatus::Drawing).unwrap();
+        assert!(game.set_status(GameStatus::Open).is_err());
+        game.set_status(GameStatus::Settled).unwrap();
+        assert!(game.set_status(GameStatus::Cancelled).is_err());
+
+        let mut game = zeroed_game();
+        game.set_status(GameStatus::Open).unwrap();
+        game.set_status(GameStatus::Drawing).unwrap();
+        game.set_status(GameStatus::Cancelled).unwrap();
+        game.set_status(GameStatus::Recreated).unwrap();
+        assert!(game.set_status(GameStatus::Open).is_err());
+    }
+
+    #[test]
+    fn covers_ticket_matches_bid_range() {
+        let mut bid = zeroed_user_bid();
+        bid.ticket_start_index = 10;
+        bid.bought_ticket_amount = 2;
+        bid.bonus_ticket_amount = 1;
+        assert!(!bid.covers_ticket(9));
+        assert!(bid.covers_ticket(10));
+        assert!(bid.covers_ticket(12));
+        assert!(!bid.covers_ticket(13));
+    }
+
+    #[test]
+    fn covers_entry_matches_wallet_range_of_unrefunded_bid() {
+        let mut bid = zeroed_user_bid();
+        bid.entry_start_index = 4;
+        bid.gbl_entry_amount = 3;
+        assert!(!bid.covers_entry(3));
+        assert!(bid.covers_entry(4));
+        assert!(bid.covers_entry(6));
+        assert!(!bid.covers_entry(7));
+        bid.funds_status = FundsStatus::Withdrawed;
+        assert_eq!(bid.draw_weight(), 0);
+        assert!(!bid.covers_entry(4));
+    }
+
+    #[test]
+    fn legacy_layouts_never_read_as_current_accounts() {
+        // old accounts are too short for the current layouts, so only the legacy path can load them
+        assert!(zeroed_game().try_to_vec().unwrap().len() > std::mem::size_of::<LegacyGame>());
+        assert!(zeroed_user_bid().try_to_vec().unwrap().len() > std::mem::size_of::<LegacyUserBid>());
+        let game = LegacyGame::deserialize(&mut &[0u8; 512][..]).unwrap();
+        assert!(game.try_to_vec().unwrap().len() <= std::mem::size_of::<LegacyGame>());
+    }
+}
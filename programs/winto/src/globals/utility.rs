@@ -1,8 +1,12 @@
 use anchor_lang::{
     prelude::*, 
-    solana_program::{self, pubkey::PUBKEY_BYTES, program_memory::sol_memcmp,  program_pack::{IsInitialized, Pack}}
+    solana_program::{self, program_option::COption, pubkey::PUBKEY_BYTES, program_memory::sol_memcmp,  program_pack::{IsInitialized, Pack}, program::invoke, system_instruction},
+    Discriminator,
 };
+use anchor_spl::token::Mint;
 use spl_token::{state::Account as SplAccount};
+use spl_token_metadata::state::{Metadata, EDITION, PREFIX};
+use std::convert::TryInto;
 use spl_associated_token_account::get_associated_token_address;
 use crate::globals::*;
 
@@ -58,4 +62,282 @@ fn assert_initialized<T: Pack + IsInitialized>(account_info: &AccountInfo) -> Re
     } else {
         Ok(account)
     }
-}
\ No newline at end of file
+}
+
+/// Returns the first slot hash at or after `target_slot` from the SlotHashes sysvar.
+/// Skipped slots have no entry, so the first produced block after the target stands in;
+/// nobody can pick another one. Fails before that block exists or once the target aged out.
+/// The account data is a u64 length followed by (u64 slot, [u8; 32] hash) entries.
+pub fn get_slot_hash_at(slot_hashes: &AccountInfo, target_slot: u64) -> Result<(u64, [u8; 32])> {
+    let data = slot_hashes.try_borrow_data()?;
+    if data.len() < 8 {
+        return err!(WinError::InvalidSlotHashes);
+    }
+    let count = u64::from_le_bytes(data[0..8].try_into().unwrap()) as usize;
+    if count == 0 || data.len() < 8 + count * 40 {
+        return err!(WinError::InvalidSlotHashes);
+    }
+    // entries are ordered from the newest slot down
+    let mut found: Option<(u64, [u8; 32])> = None;
+    for i in 0..count {
+        let offset = 8 + i * 40;
+        let slot = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        if slot < target_slot {
+            return found.ok_or(error!(WinError::DrawSlotNotReached));
+        }
+        found = Some((slot, data[offset + 8..offset + 40].try_into().unwrap()));
+        if slot == target_slot {
+            return Ok((slot, data[offset + 8..offset + 40].try_into().unwrap()));
+        }
+    }
+    // every entry is past the target, so the first block at or after it may have aged out
+    Err(error!(WinError::DrawSlotExpired))
+}
+
+/// True once the hash of a draw slot can no longer be in the SlotHashes sysvar
+pub fn is_draw_slot_expired(target_slot: u64, current_slot: u64) -> bool {
+    current_slot > target_slot.saturating_add(SLOT_HASHES_WINDOW)
+}
+
+/// Maps a draw seed to a ticket index in [0, ticket_count) for a winner slot
+pub fn draw_ticket_index(seed: &[u8; 32], winner_slot: u32, ticket_count: u32) -> u32 {
+    let slot_seed = solana_program::keccak::hashv(&[seed, &winner_slot.to_le_bytes()]).0;
+    let value = u64::from_le_bytes(slot_seed[0..8].try_into().unwrap());
+    (value % ticket_count as u64) as u32
+}
+
+/// Maps an index among the entries left after removing the taken (start, length) ranges
+/// back to its position in the full layout, so a draw never lands on a taken range
+pub fn skip_taken_entries(index: u32, taken: &mut [(u32, u32)]) -> Result<u32> {
+    taken.sort_unstable();
+    let mut position = index;
+    for (start, length) in taken.iter() {
+        if *start > position {
+            break;
+        }
+        position = position.checked_add(*length).ok_or(WinError::NumericalOverflow)?;
+    }
+    Ok(position)
+}
+
+/// Checks a game start time against the clock: not backdated beyond the
+/// tolerance and not scheduled further ahead than the max start delay.
+pub fn verify_game_start_time(start_time: u32, current_time: u32) -> Result<()> {
+    if start_time.checked_add(GAME_START_TIME_TOLERANCE).ok_or(WinError::NumericalOverflow)? < current_time {
+        return err!(WinError::InvalidStartTime);
+    }
+    if start_time > current_time.checked_add(MAX_GAME_START_DELAY).ok_or(WinError::NumericalOverflow)? {
+        return err!(WinError::InvalidStartTime);
+    }
+    Ok(())
+}
+
+/// Checks game economics shared by create and recreate: non-zero price and
+/// minimum cost, and a capped ticket supply able to reach the minimum cost.
+pub fn verify_game_economics(ticket_price: u64, minimum_cost: u64, max_tickets: u32) -> Result<()> {
+    if minimum_cost == 0 || ticket_price == 0 {
+        return err!(WinError::WrongVaule);
+    }
+    if max_tickets > 0 && (max_tickets as u128).checked_mul(ticket_price as u128).ok_or(WinError::NumericalOverflow)? < minimum_cost as u128 {
+        return err!(WinError::WrongVaule);
+    }
+    Ok(())
+}
+
+/// Sum of `k / step_size` for every ticket index `k` below `ticket_count`.
+pub fn step_index_sum(ticket_count: u128, step_size: u128) -> Result<u128> {
+    let steps = ticket_count.checked_div(step_size).ok_or(WinError::NumericalOverflow)?;
+    let rest = ticket_count.checked_rem(step_size).ok_or(WinError::NumericalOverflow)?;
+    let full_steps = step_size
+        .checked_mul(steps).ok_or(WinError::NumericalOverflow)?
+        .checked_mul(steps.saturating_sub(1)).ok_or(WinError::NumericalOverflow)?
+        / 2;
+    Ok(full_steps.checked_add(steps.checked_mul(rest).ok_or(WinError::NumericalOverflow)?).ok_or(WinError::NumericalOverflow)?)
+}
+
+/// Checks that a mint is a real NFT: no decimals, a supply of exactly one that can never grow
+/// and a Token Metadata account at the canonical metadata PDA of the mint.
+/// Supply can't grow once the mint authority is dropped or held by the master edition PDA.
+pub fn verify_nft_metadata(mint: &Account<Mint>, metadata_account: &AccountInfo) -> Result<Metadata> {
+    if mint.decimals != 0 || mint.supply != 1 {
+        return err!(WinError::InvalidPrizeNft);
+    }
+    if let COption::Some(mint_authority) = mint.mint_authority {
+        let metadata_program_id = spl_token_metadata::id();
+        let (edition_pda, _bump) = Pubkey::find_program_address(
+            &[PREFIX.as_bytes(), metadata_program_id.as_ref(), mint.key().as_ref(), EDITION.as_bytes()],
+            &metadata_program_id,
+        );
+        if mint_authority != edition_pda {
+            return err!(WinError::InvalidPrizeNft);
+        }
+    }
+    load_nft_metadata(mint.key(), metadata_account)
+}
+
+/// Loads the Token Metadata account of a mint, checking it is the canonical metadata PDA
+pub fn load_nft_metadata(mint: Pubkey, metadata_account: &AccountInfo) -> Result<Metadata> {
+    let metadata_program_id = spl_token_metadata::id();
+    let (metadata_pda, _bump) = Pubkey::find_program_address(
+        &[PREFIX.as_bytes(), metadata_program_id.as_ref(), mint.as_ref()],
+        &metadata_program_id,
+    );
+    if metadata_account.key() != metadata_pda || *metadata_account.owner != metadata_program_id {
+        return err!(WinError::WrongMetadata);
+    }
+    let metadata = match Metadata::from_account_info(metadata_account) {
+        Ok(v) => v,
+        Err(_e) => return err!(WinError::WrongMetadata),
+    };
+    if metadata.mint != mint {
+        return err!(WinError::WrongMetadata);
+    }
+    Ok(metadata)
+}
+
+/// Loads the jackpot of a payment mint if an admin created it; settlement skips the jackpot slice without one
+pub fn load_jackpot<'info>(jackpot: &AccountInfo<'info>, mint: Pubkey) -> Result<Option<Account<'info, Jackpot>>> {
+    let (jackpot_pda, _bump) = Pubkey::find_program_address(&[JACKPOT.as_ref(), &mint.to_bytes()], &crate::ID);
+    if jackpot.key() != jackpot_pda {
+        return err!(WinError::InvalidAccount);
+    }
+    if *jackpot.owner != crate::ID || jackpot.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Account::try_from(jackpot)?))
+}
+
+/// Resolves the organizer's Wings tier from a locked Wings NFT and counts the new game on the lock.
+/// No Wings mint means no tier; otherwise the mint must be locked by the organizer,
+/// which stored the tier resolved from its metadata at lock time.
+pub fn attach_wings_nft(
+    organizer_wallet: Pubkey,
+    wings_nft_mint: Pubkey,
+    user_wings_nft_locked: &AccountInfo,
+) -> Result<WingsType> {
+    if wings_nft_mint == Pubkey::default() {
+        return Ok(WingsType::None);
+    }
+    let mut locked = load_wings_nft_locked(organizer_wallet, wings_nft_mint, user_wings_nft_locked)?;
+    locked.active_game_count = locked.active_game_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+    locked.exit(&crate::ID)?;
+    Ok(locked.wings_type.clone())
+}
+
+/// Stops counting a finished game on the organizer's Wings NFT lock
+pub fn detach_wings_nft(
+    organizer_wallet: Pubkey,
+    wings_nft_mint: Pubkey,
+    user_wings_nft_locked: &AccountInfo,
+) -> Result<()> {
+    if wings_nft_mint == Pubkey::default() {
+        return Ok(());
+    }
+    let mut locked = load_wings_nft_locked(organizer_wallet, wings_nft_mint, user_wings_nft_locked)?;
+    locked.active_game_count = locked.active_game_count.checked_sub(1).ok_or(WinError::NumericalOverflow)?;
+    locked.exit(&crate::ID)?;
+    Ok(())
+}
+
+fn load_wings_nft_locked<'info>(
+    organizer_wallet: Pubkey,
+    wings_nft_mint: Pubkey,
+    user_wings_nft_locked: &AccountInfo<'info>,
+) -> Result<Account<'info, UserWingsNftLocked>> {
+    let (locked_pda, _bump) = Pubkey::find_program_address(&[&organizer_wallet.to_bytes(), &wings_nft_mint.to_bytes()], &crate::ID);
+    if user_wings_nft_locked.key() != locked_pda {
+        return err!(WinError::WingsNftNotLocked);
+    }
+    let locked: Account<UserWingsNftLocked> = Account::try_from(user_wings_nft_locked)?;
+    if locked.user_wallet != organizer_wallet || locked.wings_nft_mint != wings_nft_mint {
+        return err!(WinError::WingsNftNotLocked);
+    }
+    Ok(locked)
+}
+
+/// Grows a program account created with an older, shorter layout to the current one.
+/// The tail is zero-filled so the new fields read as defaults; the payer tops up the rent.
+pub fn migrate_account<'info, T: Discriminator>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if *account.owner != crate::ID || account.data_len() < 8 || account.try_borrow_data()?[..8] != T::discriminator() {
+        return err!(WinError::InvalidAccount);
+    }
+    if account.data_len() >= new_len {
+        return err!(WinError::AlreadyMigrated);
+    }
+    let shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, shortfall),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+/// Closes a program account that can't be loaded as an `Account`, sending its rent to `destination`
+pub fn close_raw_account<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.lamports.borrow_mut() = destination.lamports().checked_add(lamports).ok_or(WinError::NumericalOverflow)?;
+    **account.lamports.borrow_mut() = 0;
+    account.try_borrow_mut_data()?.fill(0);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_index_sum_counts_steps_of_each_ticket() {
+        assert_eq!(step_index_sum(0, 2).unwrap(), 0);
+        assert_eq!(step_index_sum(2, 2).unwrap(), 0);
+        // steps of tickets 0..5 are 0, 0, 1, 1, 2
+        assert_eq!(step_index_sum(5, 2).unwrap(), 4);
+        assert_eq!(step_index_sum(6, 3).unwrap(), 3);
+        assert!(step_index_sum(5, 0).is_err());
+    }
+
+    #[test]
+    fn draw_slot_expires_after_the_slot_hashes_window() {
+        assert!(!is_draw_slot_expired(100, 90));
+        assert!(!is_draw_slot_expired(100, 100 + SLOT_HASHES_WINDOW));
+        assert!(is_draw_slot_expired(100, 101 + SLOT_HASHES_WINDOW));
+    }
+
+    #[test]
+    fn draw_ticket_index_is_deterministic_and_in_range() {
+        let seed = [7u8; 32];
+        for winner_slot in 0..16 {
+            let index = draw_ticket_index(&seed, winner_slot, 10);
+            assert!(index < 10);
+            assert_eq!(index, draw_ticket_index(&seed, winner_slot, 10));
+        }
+        assert_eq!(draw_ticket_index(&seed, 3, 1), 0);
+    }
+
+    #[test]
+    fn draw_ticket_index_varies_with_slot() {
+        let seed = [7u8; 32];
+        let by_slot: Vec<u32> = (0..8).map(|slot| draw_ticket_index(&seed, slot, u32::MAX)).collect();
+        assert!(by_slot.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn skip_taken_entries_steps_over_winner_ranges() {
+        // layout 0..20 with wallets [2, 5) and [10, 16) taken, given out of order
+        let mut taken = [(10, 6), (2, 3)];
+        assert_eq!(skip_taken_entries(0, &mut taken).unwrap(), 0);
+        assert_eq!(skip_taken_entries(1, &mut taken).unwrap(), 1);
+        assert_eq!(skip_taken_entries(2, &mut taken).unwrap(), 5);
+        assert_eq!(skip_taken_entries(6, &mut taken).unwrap(), 9);
+        assert_eq!(skip_taken_entries(7, &mut taken).unwrap(), 16);
+        assert_eq!(skip_taken_entries(10, &mut taken).unwrap(), 19);
+        assert_eq!(skip_taken_entries(4, &mut []).unwrap(), 4);
+    }
+}
@@ -33,7 +33,7 @@ pub mod winto {
         ctx.accounts.process(win_bump, args)
     }
 
-    /// admin can change fund wallet, wings creator, community fee, emergency flag
+    /// admin can change fund wallet, wings creator, emergency flag
     pub fn update_by_admin(
         ctx: Context<UpdateByAdminWallet>,
         args: UpdateByAdminWalletArgs,
@@ -49,6 +49,33 @@ pub mod winto {
         ctx.accounts.process(args)
     }
 
+    /// admin create jackpot of a payment mint
+    pub fn create_jackpot(ctx: Context<CreateJackpot>, args: CreateJackpotArgs) -> Result<()> {
+        let bump = *ctx.bumps.get("jackpot").unwrap();
+        ctx.accounts.process(bump, args)
+    }
+
+    /// admin allowlist prize collection
+    pub fn add_prize_collection(ctx: Context<AddPrizeCollection>, args: AddPrizeCollectionArgs) -> Result<()> {
+        let bump = *ctx.bumps.get("prize_collection").unwrap();
+        ctx.accounts.process(bump, args)
+    }
+
+    /// admin remove prize collection
+    pub fn remove_prize_collection(ctx: Context<RemovePrizeCollection>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// admin update Wings tier registry, fee schedule and perks
+    pub fn update_wings_tiers(ctx: Context<UpdateWingsTiers>, args: UpdateWingsTiersArgs) -> Result<()> {
+        ctx.accounts.process(args)
+    }
+
+    /// admin migrate Win details to the layout with the Wings tier table
+    pub fn migrate_win(ctx: Context<MigrateWin>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// distribute assets by tokenomic
     pub fn assets_distribution(
         ctx: Context<AssetsDistribution>,
@@ -113,6 +140,45 @@ pub mod winto {
         ctx.accounts.process(args)
     }
 
+    /// reveal game draw
+    pub fn reveal_game_draw(
+        ctx: Context<RevealGameDraw>,
+        args: RevealGameDrawArgs,
+    ) -> Result<()> {
+        ctx.accounts.process(args)
+    }
+
+    /// lay out the draw entries of the next bidder of a closed game
+    pub fn register_game_entrant(ctx: Context<RegisterGameEntrant>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// record game winner
+    pub fn record_game_winner<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecordGameWinner<'info>>
+    ) -> Result<()> {
+        let remaining_accounts = ctx.remaining_accounts;
+        ctx.accounts.process(remaining_accounts)
+    }
+
+    /// close an ended jackpot epoch and fix its draw slot
+    pub fn close_jackpot_epoch(ctx: Context<CloseJackpotEpoch>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// hand out a prize NFT of a game in the old layout
+    pub fn return_legacy_game_prize(ctx: Context<ReturnLegacyGamePrize>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// reveal jackpot draw and start next epoch
+    pub fn reveal_jackpot_draw(
+        ctx: Context<RevealJackpotDraw>,
+        args: RevealJackpotDrawArgs,
+    ) -> Result<()> {
+        ctx.accounts.process(args)
+    }
+
     ////////////////////////////////////////////////////////////////////////
     /// Lottery
     ////////////////////////////////////////////////////////////////////////
@@ -122,13 +188,30 @@ pub mod winto {
     ///////////////////////////////
 
     /// organizer create game
-    pub fn create_game<'info>(
-        ctx: Context<'_, '_, '_, 'info, CreateGame<'info>>,
+    pub fn create_game(
+        ctx: Context<CreateGame>,
         args: CreateGameArgs
     ) -> Result<()> {
         let bump = *ctx.bumps.get("game").unwrap();
-        let remaining_accounts = ctx.remaining_accounts;
-        ctx.accounts.process(bump, args, remaining_accounts)
+        ctx.accounts.process(bump, args)
+    }
+
+    /// organizer add prize NFT to game
+    pub fn add_game_prize_nft(ctx: Context<AddGamePrizeNft>) -> Result<()> {
+        let bump = *ctx.bumps.get("game_prize").unwrap();
+        ctx.accounts.process(bump)
+    }
+
+    /// organizer add token prize to game
+    pub fn add_game_prize_token(ctx: Context<AddGamePrizeToken>, args: AddGamePrizeTokenArgs) -> Result<()> {
+        let bump = *ctx.bumps.get("game_prize").unwrap();
+        ctx.accounts.process(bump, args)
+    }
+
+    /// organizer add SOL prize to game
+    pub fn add_game_prize_sol(ctx: Context<AddGamePrizeSol>, args: AddGamePrizeSolArgs) -> Result<()> {
+        let bump = *ctx.bumps.get("game_prize").unwrap();
+        ctx.accounts.process(bump, args)
     }
 
     /// Lock Wings NFT
@@ -141,19 +224,54 @@ pub mod winto {
         ctx.accounts.process()
     }
 
+    /// migrate Wings NFT lock to the tiered layout
+    pub fn migrate_wings_nft_locked(ctx: Context<MigrateWingsNftLocked>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// release a settled or cancelled game from the organizer's game limit and Wings NFT lock
+    pub fn finish_game(ctx: Context<FinishGame>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// end game
-    pub fn end_game(ctx: Context<EndGame>, args: EndGameArgs) -> Result<()> {
-        ctx.accounts.process(args)
+    pub fn end_game(ctx: Context<EndGame>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// organizer get back NFT or token prize in cancelled game
+    pub fn organizer_get_back_nft(ctx: Context<OrganizerGetBackNft>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// organizer get back SOL prize in cancelled game
+    pub fn organizer_get_back_sol(ctx: Context<OrganizerGetBackSol>) -> Result<()> {
+        ctx.accounts.process()
     }
 
-    /// organizer get back NFT in cancelled game
-    pub fn organizer_get_back_nft<'info>(
-        ctx: Context<'_, '_, '_, 'info, OrganizerGetBackNft<'info>>
+    /// organizer cancel game before any ticket is sold, return every prize and close the game
+    pub fn cancel_game<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelGame<'info>>
     ) -> Result<()> {
         let remaining_accounts = ctx.remaining_accounts;
         ctx.accounts.process(remaining_accounts)
     }
 
+    /// organizer extend under-funded game
+    pub fn extend_game(ctx: Context<ExtendGame>, args: ExtendGameArgs) -> Result<()> {
+        ctx.accounts.process(args)
+    }
+
+    /// organizer close finished game and reclaim rent
+    pub fn close_game(ctx: Context<CloseGame>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// organizer close claimed game prize and reclaim rent
+    pub fn close_game_prize(ctx: Context<CloseGamePrize>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// organizer withdraw game money(SOL)
     pub fn organizer_process_game_sol(
         ctx: Context<OrganizerProcessGameSol>
@@ -168,6 +286,16 @@ pub mod winto {
         ctx.accounts.process()
     }
 
+    /// organizer withdraw game money(SOL) of a game in the old layout
+    pub fn settle_legacy_game_sol(ctx: Context<SettleLegacyGameSol>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// organizer withdraw game money(Token) of a game in the old layout
+    pub fn settle_legacy_game_token(ctx: Context<SettleLegacyGameToken>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// organizer recreate game
     pub fn recreate_game(ctx: Context<RecreateGame>, args: RecreateGameArgs) -> Result<()> {
         let bump = *ctx.bumps.get("game").unwrap();
@@ -184,6 +312,11 @@ pub mod winto {
         ctx.accounts.process(bump)
     }
 
+    /// migrate user details pda to the layout with the organizer game count
+    pub fn migrate_user_details(ctx: Context<MigrateUserDetails>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// User game bid
     pub fn user_game_bid_sol(ctx: Context<UserGameBidSol>, args: UserGameBidSolArgs) -> Result<()> {
         ctx.accounts.process(args)
@@ -199,11 +332,58 @@ pub mod winto {
         ctx.accounts.process()
     }
 
+    /// Winner claim token prize
+    pub fn winner_claim_token(ctx: Context<WinnerClaimToken>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// Winner claim SOL prize
+    pub fn winner_claim_sol(ctx: Context<WinnerClaimSol>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// user claim airdrop $WIN (5% or 40%)
     pub fn user_claim_airdrop_win(ctx: Context<UserClaimAirdropWin>, args: UserClaimAirdropWinArgs) -> Result<()> {
         ctx.accounts.process(args)
     }
 
+    /// user close finished bid and reclaim rent
+    pub fn close_user_bid(ctx: Context<CloseUserBid>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// user enter jackpot of current epoch
+    pub fn enter_jackpot(ctx: Context<EnterJackpot>) -> Result<()> {
+        let bump = *ctx.bumps.get("jackpot_entry").unwrap();
+        ctx.accounts.process(bump)
+    }
+
+    /// jackpot winner claim SOL
+    pub fn claim_jackpot_sol(ctx: Context<ClaimJackpotSol>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// jackpot winner claim token
+    pub fn claim_jackpot_token(ctx: Context<ClaimJackpotToken>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// user close jackpot entry of a drawn epoch
+    pub fn close_jackpot_entry(ctx: Context<CloseJackpotEntry>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// persist the status of an ended game so its draw or refunds can proceed
+    pub fn close_game_sales(ctx: Context<CloseGameSales>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// user roll refundable bid of cancelled game into recreated game
+    pub fn roll_bid_into_game(ctx: Context<RollBidIntoGame>) -> Result<()> {
+        let bump = *ctx.bumps.get("user_global_bid").unwrap();
+        ctx.accounts.process(bump)
+    }
+
     /// user withdraw funds sol
     pub fn user_withdraw_funds_sol(ctx: Context<UserWithdrawFundsSol>) -> Result<()> {
         ctx.accounts.process()
@@ -214,6 +394,16 @@ pub mod winto {
         ctx.accounts.process()
     }
 
+    /// user close bid of a game in the old layout, refunding a cancelled game(SOL)
+    pub fn refund_legacy_bid_sol(ctx: Context<RefundLegacyBidSol>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// user close bid of a game in the old layout, refunding a cancelled game(Token)
+    pub fn refund_legacy_bid_token(ctx: Context<RefundLegacyBidToken>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// stake freely NFT
     pub fn stake_freely_nft(ctx: Context<StakeFreelyNft>) -> Result<()> {
         ctx.accounts.process()
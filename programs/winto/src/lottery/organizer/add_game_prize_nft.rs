@@ -0,0 +1,136 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, TokenAccount, Token, Mint};
+use std::mem::size_of;
+
+/// organizer add a prize NFT to a game before the first ticket is sold
+#[derive(Accounts)]
+pub struct AddGamePrizeNft<'info> {
+    /// organizer
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied,
+        constraint = game.prize_game == game.key() @ WinError::InvalidAccount,
+        constraint = game.total_entries == 0 && game.total_bidders == 0 @ WinError::GameHasTickets,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda
+    #[account(
+        init,
+        seeds = [GAME_PRIZE.as_ref(), &game.key().to_bytes(), &game.prize_count.to_le_bytes()],
+        bump,
+        payer = organizer_wallet,
+        space = 8 + size_of::<GamePrize>(),
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// prize NFT
+    pub prize_nft: Box<Account<'info, Mint>>,
+
+    /// prize NFT metadata
+    /// CHECK: verified in process
+    pub prize_metadata: UncheckedAccount<'info>,
+
+    /// prize collection allowlist pda; checked only when win requires prize collections
+    /// CHECK: verified in process
+    pub prize_collection: UncheckedAccount<'info>,
+
+    /// organizer nft ata
+    #[account(
+        mut,
+        constraint = organizer_nft_ata.mint == prize_nft.key() @ WinError::InvalidTokenMint,
+        constraint = organizer_nft_ata.owner == organizer_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub organizer_nft_ata: Box<Account<'info, TokenAccount>>,
+
+    /// NFT Pool
+    /// CHECK: Safe account
+    #[account(
+        seeds = [NFT_POOL.as_ref()],
+        bump
+    )]
+    pub nft_pool: UncheckedAccount<'info>,
+
+    /// NFT Pool ata of nft
+    #[account(
+        mut,
+        constraint = nft_pool_ata.mint == prize_nft.key() @ WinError::InvalidTokenMint,
+        constraint = nft_pool_ata.owner == nft_pool.key() @ WinError::InvalidTokenOwner
+    )]
+    pub nft_pool_ata: Box<Account<'info, TokenAccount>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AddGamePrizeNft<'info> {
+    pub fn process(&mut self, bump: u8) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status != GameStatus::Scheduled && self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
+        let metadata = verify_nft_metadata(&self.prize_nft, &self.prize_metadata)?;
+        if self.win.require_prize_collection {
+            let prize_collection: Account<PrizeCollection> = Account::try_from(&self.prize_collection)?;
+            let (collection_pda, _bump) = Pubkey::find_program_address(&[PRIZE_COLLECTION.as_ref(), &prize_collection.creator.to_bytes()], &crate::ID);
+            if prize_collection.key() != collection_pda {
+                return Err(error!(WinError::PrizeCollectionNotAllowed));
+            }
+            let verified = match metadata.data.creators {
+                Some(creators) => creators.iter().any(|creator| creator.verified && creator.address == prize_collection.creator),
+                None => false,
+            };
+            if !verified {
+                return Err(error!(WinError::PrizeCollectionNotAllowed));
+            }
+        }
+
+        // transfer NFT to NFT-Pool
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.organizer_nft_ata.to_account_info(),
+                    to: self.nft_pool_ata.to_account_info(),
+                    authority: self.organizer_wallet.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        self.game_prize.game = self.game.key();
+        self.game.verify_prize_limit(self.win.wings_tier(&self.game.wings_type).max_prizes, 1)?;
+        self.game_prize.index = self.game.prize_count;
+        self.game_prize.mint_address = self.prize_nft.key();
+        self.game_prize.prize_type = PrizeType::Nft;
+        self.game_prize.amount = 1;
+        self.game_prize.winner_wallet = Pubkey::default();
+        self.game_prize.is_claimed = false;
+        self.game_prize.bump = bump;
+        self.game.prize_count = self.game.prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke, system_instruction};
+use std::mem::size_of;
+
+/// organizer add a SOL prize to a game before the first ticket is sold; the game prize pda holds the lamports
+#[derive(Accounts)]
+pub struct AddGamePrizeSol<'info> {
+    /// organizer
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied,
+        constraint = game.prize_game == game.key() @ WinError::InvalidAccount,
+        constraint = game.total_entries == 0 && game.total_bidders == 0 @ WinError::GameHasTickets,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda
+    #[account(
+        init,
+        seeds = [GAME_PRIZE.as_ref(), &game.key().to_bytes(), &game.prize_count.to_le_bytes()],
+        bump,
+        payer = organizer_wallet,
+        space = 8 + size_of::<GamePrize>(),
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddGamePrizeSol<'info> {
+    pub fn process(&mut self, bump: u8, args: AddGamePrizeSolArgs) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status != GameStatus::Scheduled && self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
+        if args.amount == 0 {
+            return Err(error!(WinError::WrongVaule));
+        }
+        if **self.organizer_wallet.lamports.borrow() < args.amount {
+            return Err(error!(WinError::InsufficientSolBalance));
+        }
+
+        invoke(
+            &system_instruction::transfer(
+                self.organizer_wallet.key,
+                &self.game_prize.key(),
+                args.amount,
+            ),
+            &[
+                self.organizer_wallet.to_account_info().clone(),
+                self.game_prize.to_account_info().clone(),
+                self.system_program.to_account_info().clone(),
+            ],
+        )?;
+
+        self.game_prize.game = self.game.key();
+        self.game.verify_prize_limit(self.win.wings_tier(&self.game.wings_type).max_prizes, 1)?;
+        self.game_prize.index = self.game.prize_count;
+        self.game_prize.mint_address = Pubkey::default();
+        self.game_prize.prize_type = PrizeType::Sol;
+        self.game_prize.amount = args.amount;
+        self.game_prize.winner_wallet = Pubkey::default();
+        self.game_prize.is_claimed = false;
+        self.game_prize.bump = bump;
+        self.game.prize_count = self.game.prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct AddGamePrizeSolArgs {
+    /// prize lamports
+    pub amount: u64,
+}
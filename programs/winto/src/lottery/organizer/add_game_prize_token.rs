@@ -0,0 +1,125 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, TokenAccount, Token, Mint};
+use std::mem::size_of;
+
+/// organizer add a token prize to a game before the first ticket is sold
+#[derive(Accounts)]
+pub struct AddGamePrizeToken<'info> {
+    /// organizer
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied,
+        constraint = game.prize_game == game.key() @ WinError::InvalidAccount,
+        constraint = game.total_entries == 0 && game.total_bidders == 0 @ WinError::GameHasTickets,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda
+    #[account(
+        init,
+        seeds = [GAME_PRIZE.as_ref(), &game.key().to_bytes(), &game.prize_count.to_le_bytes()],
+        bump,
+        payer = organizer_wallet,
+        space = 8 + size_of::<GamePrize>(),
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// prize token mint
+    pub prize_mint: Box<Account<'info, Mint>>,
+
+    /// organizer token ata
+    #[account(
+        mut,
+        constraint = organizer_token_ata.mint == prize_mint.key() @ WinError::InvalidTokenMint,
+        constraint = organizer_token_ata.owner == organizer_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub organizer_token_ata: Box<Account<'info, TokenAccount>>,
+
+    /// NFT Pool
+    /// CHECK: Safe account
+    #[account(
+        seeds = [NFT_POOL.as_ref()],
+        bump
+    )]
+    pub nft_pool: UncheckedAccount<'info>,
+
+    /// NFT Pool ata of prize token
+    #[account(
+        mut,
+        constraint = nft_pool_ata.mint == prize_mint.key() @ WinError::InvalidTokenMint,
+        constraint = nft_pool_ata.owner == nft_pool.key() @ WinError::InvalidTokenOwner
+    )]
+    pub nft_pool_ata: Box<Account<'info, TokenAccount>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AddGamePrizeToken<'info> {
+    pub fn process(&mut self, bump: u8, args: AddGamePrizeTokenArgs) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status != GameStatus::Scheduled && self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
+        if args.amount == 0 {
+            return Err(error!(WinError::WrongVaule));
+        }
+        if self.organizer_token_ata.amount < args.amount {
+            return Err(error!(WinError::InsufficientTokenBalance));
+        }
+
+        // transfer token prize to NFT-Pool
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.organizer_token_ata.to_account_info(),
+                    to: self.nft_pool_ata.to_account_info(),
+                    authority: self.organizer_wallet.to_account_info(),
+                },
+            ),
+            args.amount,
+        )?;
+
+        self.game_prize.game = self.game.key();
+        self.game.verify_prize_limit(self.win.wings_tier(&self.game.wings_type).max_prizes, 1)?;
+        self.game_prize.index = self.game.prize_count;
+        self.game_prize.mint_address = self.prize_mint.key();
+        self.game_prize.prize_type = PrizeType::Token;
+        self.game_prize.amount = args.amount;
+        self.game_prize.winner_wallet = Pubkey::default();
+        self.game_prize.is_claimed = false;
+        self.game_prize.bump = bump;
+        self.game.prize_count = self.game.prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct AddGamePrizeTokenArgs {
+    /// prize token amount
+    pub amount: u64,
+}
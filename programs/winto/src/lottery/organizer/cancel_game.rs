@@ -0,0 +1,131 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, TokenAccount, Token};
+
+/// organizer cancel game before any ticket is sold
+#[derive(Accounts)]
+pub struct CancelGame<'info> {
+    /// organizer wallet
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// NFT Pool
+    /// CHECK: Safe account
+    #[account(
+        seeds = [NFT_POOL.as_ref()],
+        bump
+    )]
+    pub nft_pool: UncheckedAccount<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        close = organizer_wallet,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied,
+        constraint = game.is_nft_unstaked == false @ WinError::NftAlreadyClaimed,
+        constraint = game.total_entries == 0 && game.total_bidders == 0 @ WinError::GameHasTickets,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// user details pda
+    #[account(
+        mut,
+        seeds = [USER_DETAILS.as_ref(), &organizer_wallet.key().to_bytes()],
+        bump = user_details.bump,
+    )]
+    pub user_details: Box<Account<'info, UserDetails>>,
+
+    /// User Wings NFT locked pda; unused without a Wings NFT
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub user_wings_nft_locked: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelGame<'info> {
+    pub fn process(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status != GameStatus::Scheduled && self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
+        // every escrowed prize comes back before the game PDA is closed:
+        // (game prize pda, NFT pool ata, organizer ata) per prize, the atas unused for SOL prizes
+        if remaining_accounts.len() != (self.game.prize_count as usize).checked_mul(3).ok_or(WinError::NumericalOverflow)? {
+            return Err(error!(WinError::InvalidArgs));
+        }
+
+        // authority bump seed
+        let (_pda, bump_seed) = Pubkey::find_program_address(&[NFT_POOL.as_ref()], &crate::ID);
+
+        for (i, prize_accounts) in remaining_accounts.chunks(3).enumerate() {
+            let (prize_pda, _bump) = Pubkey::find_program_address(
+                &[GAME_PRIZE.as_ref(), &self.game.prize_game.to_bytes(), &(i as u32).to_le_bytes()],
+                &crate::ID,
+            );
+            if prize_accounts[0].key() != prize_pda {
+                return Err(error!(WinError::InvalidAccount));
+            }
+            let game_prize: Account<GamePrize> = Account::try_from(&prize_accounts[0])?;
+            if game_prize.is_claimed {
+                return Err(error!(WinError::NftAlreadyClaimed));
+            }
+
+            if game_prize.prize_type != PrizeType::Sol {
+                let nft_pool_ata: Account<TokenAccount> = Account::try_from(&prize_accounts[1])?;
+                if nft_pool_ata.mint != game_prize.mint_address {
+                    return Err(error!(WinError::InvalidTokenMint));
+                }
+                if nft_pool_ata.owner != self.nft_pool.key() {
+                    return Err(error!(WinError::InvalidTokenOwner));
+                }
+                let organizer_ata: Account<TokenAccount> = Account::try_from(&prize_accounts[2])?;
+                if organizer_ata.mint != game_prize.mint_address {
+                    return Err(error!(WinError::InvalidTokenMint));
+                }
+                if organizer_ata.owner != self.organizer_wallet.key() {
+                    return Err(error!(WinError::InvalidTokenOwner));
+                }
+
+                // transfer NFT or token prize back to organizer
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        Transfer {
+                            from: prize_accounts[1].to_account_info(),
+                            to: prize_accounts[2].to_account_info(),
+                            authority: self.nft_pool.to_account_info(),
+                        },
+                        &[&[NFT_POOL.as_ref(), &[bump_seed]]],
+                    ),
+                    game_prize.amount,
+                )?;
+            }
+
+            // closing the game prize pda returns a SOL prize with its rent
+            game_prize.close(self.organizer_wallet.to_account_info())?;
+        }
+
+        self.game.set_status(GameStatus::Cancelled)?;
+
+        // nothing was sold, so the game is finished and closed right away
+        detach_wings_nft(self.organizer_wallet.key(), self.game.wings_nft_mint_address, &self.user_wings_nft_locked)?;
+        self.user_details.release_game()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// organizer close a finished game and reclaim rent
+#[derive(Accounts)]
+pub struct CloseGame<'info> {
+    /// organizer wallet
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        close = organizer_wallet,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> CloseGame<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.game.update_status()?;
+        let is_closable = match self.game.status {
+            GameStatus::Settled => {
+                self.game.funds_status != FundsStatus::NotClaimed &&
+                self.game.claimed_prize_count == self.game.prize_count &&
+                self.game.closed_prize_count == self.game.prize_count
+            }
+            GameStatus::Cancelled => {
+                self.game.is_nft_unstaked &&
+                (self.game.total_entries == 0 || self.game.is_cancel_final || self.game.is_past_extension_grace(self.win.game_extension_grace_period)?)
+            }
+            GameStatus::Recreated => true,
+            _ => false,
+        };
+        // finish_game must release the game first
+        if !self.game.is_released {
+            return Err(error!(WinError::AccountInUse));
+        }
+        if !is_closable {
+            return Err(error!(WinError::AccountInUse));
+        }
+
+        // bids left open by their owners stop blocking the close after the bid close period;
+        // they are still closable once the game is gone, but pending refunds keep the game open
+        if self.game.bid_account_count > 0 {
+            if !self.game.are_bids_final(self.win.game_extension_grace_period)? || !self.game.is_past_bid_close_period(self.win.game_extension_grace_period)? {
+                return Err(error!(WinError::AccountInUse));
+            }
+            if self.game.status != GameStatus::Settled && self.game.total_bidders > 0 {
+                return Err(error!(WinError::AccountInUse));
+            }
+        }
+
+        Ok(())
+    }
+}
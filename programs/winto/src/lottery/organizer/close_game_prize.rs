@@ -0,0 +1,52 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// organizer close a claimed prize of a settled game and reclaim rent
+#[derive(Accounts)]
+pub struct CloseGamePrize<'info> {
+    /// organizer wallet
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied,
+        constraint = game.status == GameStatus::Settled @ WinError::NotDrawnYet,
+        // winner slot prizes are read by the claims of other prizes until every winner is recorded
+        constraint = game.recorded_winner_count == game.winner_count @ WinError::AccountInUse,
+        constraint = game.claimed_prize_count == game.prize_count @ WinError::AccountInUse
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda
+    #[account(
+        mut,
+        close = organizer_wallet,
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game_prize.index.to_le_bytes()],
+        bump = game_prize.bump,
+        constraint = game_prize.is_claimed @ WinError::AccountInUse
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> CloseGamePrize<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.game.closed_prize_count = self.game.closed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+}
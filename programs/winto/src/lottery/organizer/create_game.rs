@@ -1,6 +1,5 @@
 use crate::globals::*;
-use anchor_lang::{prelude::*};
-use anchor_spl::token::{self, Transfer, Token};
+use anchor_lang::{prelude::*, solana_program::clock};
 use std::mem::size_of;
 
 /// organizer create game
@@ -17,18 +16,10 @@ pub struct CreateGame<'info> {
     )]
     pub bot_wallet: Signer<'info>,
 
-    /// NFT Pool
-    /// CHECK: Safe account
-    #[account(
-        seeds = [NFT_POOL.as_ref()],
-        bump
-    )]
-    pub nft_pool: UncheckedAccount<'info>,
-
     /// game pda
     #[account(
         init,
-        seeds = [GAME.as_ref(), &args.game_time_stamp.to_le_bytes(), &organizer_wallet.key().to_bytes()],
+        seeds = [GAME.as_ref(), &args.game_nonce.to_le_bytes(), &organizer_wallet.key().to_bytes()],
         bump,
         payer = organizer_wallet,
         space = 8 + size_of::<Game>(),
@@ -45,63 +36,98 @@ pub struct CreateGame<'info> {
 
     /// user details pda
     #[account(
+        mut,
         seeds = [USER_DETAILS.as_ref(), &organizer_wallet.key().to_bytes()],
         bump = user_details.bump,
         constraint = user_details.user_wallet == organizer_wallet.key() @ WinError::AccessDenied,
-        constraint = user_details.verify_create_game(args.proof, organizer_wallet.key(), win.organizer_whitelist_merkle_root, args.wings_type) @ WinError::UnableToCreateGame
+        constraint = user_details.verify_create_game(args.proof, organizer_wallet.key(), win.organizer_whitelist_merkle_root, args.wings_nft_mint_address) @ WinError::UnableToCreateGame
     )]
     pub user_details: Box<Account<'info, UserDetails>>,
 
+    /// User Wings NFT locked pda; unused without a Wings NFT
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub user_wings_nft_locked: UncheckedAccount<'info>,
+
     /// system program
     pub system_program: Program<'info, System>,
-
-    /// token program
-    pub token_program: Program<'info, Token>,
 }
 
 impl<'info> CreateGame<'info> {
-    pub fn process(&mut self, bump: u8, args: CreateGameArgs, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
-        if args.minimum_cost <= 0 || args.ticket_price <= 0 {
-            return Err(error!(WinError::WrongVaule));
+    pub fn process(&mut self, bump: u8, args: CreateGameArgs) -> Result<()> {
+        if args.duration == 0 {
+            return Err(error!(WinError::InvalidArgs));
         }
-        for (i, _user_nft_ata) in remaining_accounts.iter().enumerate().step_by(3) {
-            if i % 3 == 0 {
-                assert_is_ata(&remaining_accounts[i], &self.organizer_wallet.key(), &remaining_accounts[i+2].to_account_info().key())?;
-                assert_is_ata(&remaining_accounts[i+1], &self.nft_pool.key(), &remaining_accounts[i+2].to_account_info().key())?;
-
-                // transfer NFT to NFT-Pool
-                token::transfer(
-                    CpiContext::new(
-                        self.token_program.to_account_info(),
-                        Transfer {
-                            from: remaining_accounts[i].to_account_info(),
-                            to: remaining_accounts[i+1].to_account_info(),
-                            authority: self.organizer_wallet.to_account_info(),
-                        },
-                    ),
-                    1,
-                )?;
-                let index = i.checked_div(3).unwrap();
-                self.game.nft_mint_address[index] = remaining_accounts[i+2].to_account_info().key();
-            }
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        verify_game_start_time(args.start_time, current_time)?;
+        let anti_snipe_max_extension = args.anti_snipe_max_extension.unwrap_or(0);
+        if anti_snipe_max_extension > MAX_ANTI_SNIPE_EXTENSION {
+            return Err(error!(WinError::ExtensionLimitExceeded));
         }
+        let max_tickets = args.max_tickets.unwrap_or(0);
 
         self.game.nft_owner_wallet = self.organizer_wallet.key();
         self.game.ticket_token_address = args.ticket_token_address;
         self.game.ticket_price = args.ticket_price;
         self.game.minimum_cost = args.minimum_cost;
-        self.game.opened_timestamp = args.game_time_stamp;
+        self.game.opened_timestamp = args.start_time;
         self.game.duration = args.duration;
         self.game.current_total_tickets = 0;
         self.game.current_total_bonus_tickets = 0;
         self.game.coin_type = args.coin_type;
         self.game.is_nft_unstaked = false;
         self.game.funds_status = FundsStatus::NotClaimed;
-        self.game.wings_type = args.wings_type;
+        self.game.wings_type = attach_wings_nft(
+            self.organizer_wallet.key(),
+            args.wings_nft_mint_address,
+            &self.user_wings_nft_locked,
+        )?;
         self.game.bump = bump;
-        self.game.winner_nft_count = [1,0,0,0,0];
-        self.game.winner_random_number = [0;6];
+        self.game.winner_mode = args.winner_mode;
+        // prize NFTs are added with add_game_prize_nft before the first ticket is sold
+        self.game.prize_count = 0;
+        self.game.claimed_prize_count = 0;
+        self.game.prize_game = self.game.key();
+        self.game.total_bidders = 0;
+        self.game.total_entries = 0;
+        self.game.extended_duration = 0;
+        self.game.refund_window_end = 0;
+        self.game.max_tickets = max_tickets;
+        self.game.max_tickets_per_wallet = args.max_tickets_per_wallet.unwrap_or(0);
+        self.game.anti_snipe_window = args.anti_snipe_window.unwrap_or(0);
+        self.game.anti_snipe_extension = args.anti_snipe_extension.unwrap_or(0);
+        self.game.anti_snipe_max_extension = anti_snipe_max_extension;
+        self.game.anti_snipe_extended = 0;
+        self.game.bid_account_count = 0;
+        self.game.previous_game = Pubkey::default();
+        self.game.pricing_mode = args.pricing_mode;
+        self.game.price_increment = args.price_increment.unwrap_or(0);
+        self.game.price_step_size = args.price_step_size.unwrap_or(0);
+        self.game.floor_price = args.floor_price.unwrap_or(0);
+        self.game.total_paid = 0;
+        self.game.bundle_tiers = args.bundle_tiers;
+        self.game.verify_payment()?;
+        self.game.verify_time_bounds()?;
+        self.game.verify_pricing()?;
         self.game.wings_nft_mint_address = args.wings_nft_mint_address;
+        self.game.is_released = false;
+        self.game.is_cancel_final = false;
+        self.game.closed_prize_count = 0;
+        self.game.entrant_count = 0;
+        self.game.registered_entrant_count = 0;
+        self.game.registered_entries = 0;
+        self.game.winner_entries = 0;
+        self.game.drawing_started_at = 0;
+        let wings_tier = self.win.wings_tier(&self.game.wings_type);
+        self.user_details.open_game(&wings_tier, args.duration)?;
+        self.user_details.use_game_nonce(args.game_nonce)?;
+        self.game.snapshot_fee_terms(&self.win)?;
+        self.game.draw_commitment = args.draw_commitment;
+        self.game.status = if args.start_time > current_time {
+            GameStatus::Scheduled
+        } else {
+            GameStatus::Open
+        };
         Ok(())
     }
 }
@@ -120,8 +146,11 @@ pub struct CreateGameArgs {
     /// proof
     pub proof: Vec<[u8; 32]>,
 
-    /// game time stamp
-    pub game_time_stamp: u32,
+    /// game nonce for pda seed
+    pub game_nonce: u32,
+
+    /// game start time
+    pub start_time: u32,
 
     /// duration
     pub duration: u32,
@@ -129,9 +158,42 @@ pub struct CreateGameArgs {
     /// coin type
     pub coin_type: CoinType,
 
-    /// wings type
-    pub wings_type: WingsType,
-
     /// Wings NFT mint address
-    pub wings_nft_mint_address: Pubkey
+    pub wings_nft_mint_address: Pubkey,
+
+    /// bot draw commitment
+    pub draw_commitment: [u8;32],
+
+    /// winner mode
+    pub winner_mode: WinnerMode,
+
+    /// max ticket supply
+    pub max_tickets: Option<u32>,
+
+    /// max tickets per wallet
+    pub max_tickets_per_wallet: Option<u32>,
+
+    /// anti-snipe window
+    pub anti_snipe_window: Option<u32>,
+
+    /// anti-snipe extension per late bid
+    pub anti_snipe_extension: Option<u32>,
+
+    /// max total anti-snipe extension
+    pub anti_snipe_max_extension: Option<u32>,
+
+    /// pricing mode
+    pub pricing_mode: PricingMode,
+
+    /// price increase per ticket or per step
+    pub price_increment: Option<u64>,
+
+    /// tickets per price step
+    pub price_step_size: Option<u32>,
+
+    /// dutch floor price
+    pub floor_price: Option<u64>,
+
+    /// volume discount tiers
+    pub bundle_tiers: [BundleTier; MAX_BUNDLE_TIERS],
 }
\ No newline at end of file
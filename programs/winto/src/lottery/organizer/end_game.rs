@@ -1,9 +1,8 @@
 use crate::globals::*;
-use anchor_lang::{prelude::*, solana_program::clock};
+use anchor_lang::prelude::*;
 
 /// end game
 #[derive(Accounts)]
-#[instruction(args: EndGameArgs)]
 pub struct EndGame<'info> {
     /// organizer wallet
     pub organizer_wallet: Signer<'info>,
@@ -32,28 +31,13 @@ pub struct EndGame<'info> {
 }
 
 impl<'info> EndGame<'info> {
-    pub fn process(&mut self, args: EndGameArgs) -> Result<()> {
-        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
-        if current_time
-            > self
-                .game
-                .opened_timestamp
-                .checked_add(self.game.duration)
-                .ok_or(WinError::NumericalOverflow)?
-        {
+    pub fn process(&mut self) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status != GameStatus::Open {
             return Err(error!(WinError::AlreadyGameEnd));
-        } else {
-            self.game.duration = current_time.checked_sub(self.game.opened_timestamp).ok_or(WinError::NumericalOverflow)?
         }
-        let cur_ticket_money = (self.game.ticket_price as u128).checked_mul(self.game.current_total_tickets as u128).ok_or(WinError::NumericalOverflow)? as u64;
-        self.game.minimum_cost = cur_ticket_money;
-        self.game.winner_random_number = args.random_number;
+        // organizer accepts the current ticket money and closes sales now
+        self.game.start_drawing()?;
         Ok(())
     }
 }
-
-#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
-pub struct EndGameArgs {
-    /// winner random number
-    pub random_number: [u32;6],
-}
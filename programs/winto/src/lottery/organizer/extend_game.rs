@@ -0,0 +1,84 @@
+use crate::globals::*;
+use anchor_lang::{prelude::*, solana_program::clock};
+
+/// organizer extend under-funded game
+#[derive(Accounts)]
+pub struct ExtendGame<'info> {
+    /// organizer wallet
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied,
+        constraint = game.is_nft_unstaked == false @ WinError::NoGameNft,
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> ExtendGame<'info> {
+    pub fn process(&mut self, args: ExtendGameArgs) -> Result<()> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        self.game.update_status()?;
+
+        if self.game.status == GameStatus::Cancelled {
+            if self.game.is_cancel_final {
+                return Err(error!(WinError::GameIsNotOpenedStatus));
+            }
+            // only a just-expired game can be extended in place
+            let end_time = self.game.end_time()?;
+            if current_time >= end_time.checked_add(self.win.game_extension_grace_period).ok_or(WinError::NumericalOverflow)? {
+                return Err(error!(WinError::GameIsNotOpenedStatus));
+            }
+            if self.game.claimed_prize_count > 0 {
+                return Err(error!(WinError::NftAlreadyClaimed));
+            }
+        } else if self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
+        if self.game.total_earn()? >= self.game.minimum_cost {
+            return Err(error!(WinError::GameIsFunded));
+        }
+        if args.extra_duration == 0 {
+            return Err(error!(WinError::InvalidArgs));
+        }
+
+        self.game.extended_duration = self.game.extended_duration.checked_add(args.extra_duration).ok_or(WinError::NumericalOverflow)?;
+        if self.game.extended_duration > self.win.max_game_extension {
+            return Err(error!(WinError::ExtensionLimitExceeded));
+        }
+        self.game.duration = self.game.duration.checked_add(args.extra_duration).ok_or(WinError::NumericalOverflow)?;
+        self.win.wings_tier(&self.game.wings_type).verify_duration(self.game.duration)?;
+        self.game.verify_time_bounds()?;
+        if !self.game.verify_game_time()? {
+            return Err(error!(WinError::InvalidArgs));
+        }
+
+        if self.game.status == GameStatus::Cancelled {
+            self.game.set_status(GameStatus::Open)?;
+        }
+        // existing bids carry over; bidders can opt out with a refund for a while
+        self.game.refund_window_end = current_time.checked_add(self.win.extension_refund_window).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct ExtendGameArgs {
+    /// extra duration
+    pub extra_duration: u32,
+}
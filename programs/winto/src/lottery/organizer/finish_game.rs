@@ -0,0 +1,64 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// organizer release a finished game from the game limit and Wings NFT lock
+#[derive(Accounts)]
+pub struct FinishGame<'info> {
+    /// organizer wallet
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::AccessDenied,
+        constraint = game.is_released == false @ WinError::ClaimedAlready
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// user details pda
+    #[account(
+        mut,
+        seeds = [USER_DETAILS.as_ref(), &organizer_wallet.key().to_bytes()],
+        bump = user_details.bump,
+    )]
+    pub user_details: Box<Account<'info, UserDetails>>,
+
+    /// User Wings NFT locked pda; unused without a Wings NFT
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub user_wings_nft_locked: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> FinishGame<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.game.update_status()?;
+        // a cancelled game can still be extended and reopened within the grace period
+        let is_finished = match self.game.status {
+            GameStatus::Settled | GameStatus::Recreated => true,
+            GameStatus::Cancelled => self.game.is_cancel_final || self.game.is_past_extension_grace(self.win.game_extension_grace_period)?,
+            _ => false,
+        };
+        if !is_finished {
+            return Err(error!(WinError::AccountInUse));
+        }
+
+        detach_wings_nft(self.organizer_wallet.key(), self.game.wings_nft_mint_address, &self.user_wings_nft_locked)?;
+        self.user_details.release_game()?;
+        self.game.is_released = true;
+        Ok(())
+    }
+}
@@ -95,8 +95,10 @@ impl<'info> LockWingsNft<'info> {
 
         let nft_creators = metadata.data.creators;
         let mut verified = false;
+        let mut wings_type = None;
         match nft_creators {
             Some(creators) => {
+                wings_type = self.win.wings_tier_of(&creators);
                 for (_i, creator) in creators.iter().enumerate() {
                     if creator.verified == true && creator.address == self.win.wings_creator {
                         verified = true;
@@ -112,6 +114,10 @@ impl<'info> LockWingsNft<'info> {
         if !verified {
             return Err(error!(WinError::NoWingsNft));
         }
+        let wings_type = match wings_type {
+            Some(v) => v,
+            None => return Err(error!(WinError::UnknownWingsTier)),
+        };
 
         // lock Wings NFT
         token::transfer(
@@ -130,6 +136,8 @@ impl<'info> LockWingsNft<'info> {
 
         self.user_wings_nft_locked.user_wallet = self.organizer_wallet.key();
         self.user_wings_nft_locked.wings_nft_mint = self.mint_nft.key();
+        self.user_wings_nft_locked.wings_type = wings_type;
+        self.user_wings_nft_locked.active_game_count = 0;
         Ok(())
     }
 }
\ No newline at end of file
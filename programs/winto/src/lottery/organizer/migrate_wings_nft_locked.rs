@@ -0,0 +1,77 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use std::mem::size_of;
+
+/// migrate a Wings NFT lock made before tiers were stored on it
+#[derive(Accounts)]
+pub struct MigrateWingsNftLocked<'info> {
+    /// organizer wallet
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    // Wings NFT mint
+    pub mint_nft: Box<Account<'info, Mint>>,
+
+    /// Wings NFT metadata
+    /// CHECK: verified in process
+    pub nft_metadata_account: UncheckedAccount<'info>,
+
+    /// User Wings NFT locked pda in the old layout
+    /// CHECK: verified in process
+    #[account(
+        mut,
+        seeds = [&organizer_wallet.key().to_bytes(), &mint_nft.key().to_bytes()],
+        bump,
+    )]
+    pub user_wings_nft_locked: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateWingsNftLocked<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        migrate_account::<UserWingsNftLocked>(
+            &self.user_wings_nft_locked,
+            &self.organizer_wallet,
+            &self.system_program,
+            8 + size_of::<UserWingsNftLocked>(),
+        )?;
+
+        // resolve the tier the way lock_wings_nft does now
+        let metadata = load_nft_metadata(self.mint_nft.key(), &self.nft_metadata_account)?;
+        let creators = metadata.data.creators.unwrap_or_default();
+        if !creators.iter().any(|creator| creator.verified && creator.address == self.win.wings_creator) {
+            return Err(error!(WinError::NoWingsNft));
+        }
+        let wings_type = match self.win.wings_tier_of(&creators) {
+            Some(v) => v,
+            None => return Err(error!(WinError::UnknownWingsTier)),
+        };
+
+        let mut user_wings_nft_locked: Account<UserWingsNftLocked> = Account::try_from(&self.user_wings_nft_locked)?;
+        if user_wings_nft_locked.user_wallet != self.organizer_wallet.key() || user_wings_nft_locked.wings_nft_mint != self.mint_nft.key() {
+            return Err(error!(WinError::WingsNftNotLocked));
+        }
+        // games created before the tiered layout were never counted against the lock
+        user_wings_nft_locked.wings_type = wings_type;
+        user_wings_nft_locked.active_game_count = 0;
+        user_wings_nft_locked.exit(&crate::ID)?;
+        Ok(())
+    }
+}
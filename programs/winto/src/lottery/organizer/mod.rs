@@ -2,16 +2,40 @@ pub mod create_game;
 pub mod end_game;
 pub mod organizer_process_game_sol;
 pub mod organizer_process_game_token;
+pub mod settle_legacy_game_sol;
+pub mod settle_legacy_game_token;
 pub mod lock_wings_nft;
 pub mod unlock_wings_nft;
+pub mod migrate_wings_nft_locked;
 pub mod recreate_game;
 pub mod organizer_get_back_nft;
+pub mod cancel_game;
+pub mod extend_game;
+pub mod close_game;
+pub mod close_game_prize;
+pub mod add_game_prize_nft;
+pub mod add_game_prize_token;
+pub mod add_game_prize_sol;
+pub mod organizer_get_back_sol;
+pub mod finish_game;
 
 pub use create_game::*;
 pub use end_game::*;
 pub use organizer_process_game_sol::*;
 pub use organizer_process_game_token::*;
+pub use settle_legacy_game_sol::*;
+pub use settle_legacy_game_token::*;
 pub use lock_wings_nft::*;
 pub use unlock_wings_nft::*;
+pub use migrate_wings_nft_locked::*;
 pub use recreate_game::*;
-pub use organizer_get_back_nft::*;
\ No newline at end of file
+pub use organizer_get_back_nft::*;
+pub use cancel_game::*;
+pub use extend_game::*;
+pub use close_game::*;
+pub use close_game_prize::*;
+pub use add_game_prize_nft::*;
+pub use add_game_prize_token::*;
+pub use add_game_prize_sol::*;
+pub use organizer_get_back_sol::*;
+pub use finish_game::*;
\ No newline at end of file
@@ -1,11 +1,12 @@
 use crate::globals::*;
-use anchor_lang::{prelude::*, solana_program::clock};
-use anchor_spl::token::{self, Transfer, Token};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, TokenAccount, Token};
 
-/// organizer get back NFT
+/// organizer get back NFT or token prize
 #[derive(Accounts)]
 pub struct OrganizerGetBackNft<'info> {
     /// winner
+    #[account(mut)]
     pub organizer_wallet: Signer<'info>,
 
     /// bot wallet
@@ -14,6 +15,14 @@ pub struct OrganizerGetBackNft<'info> {
     )]
     pub bot_wallet: Signer<'info>,
 
+    /// organizer nft ata
+    #[account(
+        mut,
+        constraint = organizer_nft_ata.mint == game_prize.mint_address @ WinError::InvalidTokenMint,
+        constraint = organizer_nft_ata.owner == organizer_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub organizer_nft_ata: Box<Account<'info, TokenAccount>>,
+
     /// NFT Pool
     /// CHECK: Safe account
     #[account(
@@ -22,6 +31,14 @@ pub struct OrganizerGetBackNft<'info> {
     )]
     pub nft_pool: UncheckedAccount<'info>,
 
+    /// NFT Pool ata of nft
+    #[account(
+        mut,
+        constraint = nft_pool_ata.mint == game_prize.mint_address @ WinError::InvalidTokenMint,
+        constraint = nft_pool_ata.owner == nft_pool.key() @ WinError::InvalidTokenOwner
+    )]
+    pub nft_pool_ata: Box<Account<'info, TokenAccount>>,
+
     /// game pda
     #[account(
         mut,
@@ -30,6 +47,17 @@ pub struct OrganizerGetBackNft<'info> {
     )]
     pub game: Box<Account<'info, Game>>,
 
+    /// game prize pda
+    #[account(
+        mut,
+        close = organizer_wallet,
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game_prize.index.to_le_bytes()],
+        bump = game_prize.bump,
+        constraint = game_prize.is_claimed == false @ WinError::NftAlreadyClaimed,
+        constraint = game_prize.prize_type != PrizeType::Sol @ WinError::InvalidPrizeType
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
     /// Win details PDA
     #[account(
         seeds = [WIN.as_ref()],
@@ -43,36 +71,32 @@ pub struct OrganizerGetBackNft<'info> {
 }
 
 impl<'info> OrganizerGetBackNft<'info> {
-    pub fn process(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
-        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
-        let total_earn = (self.game.ticket_price as u128).checked_mul(self.game.current_total_tickets as u128).ok_or(WinError::NumericalOverflow)? as u64;
+    pub fn process(&mut self) -> Result<()> {
+        self.game.update_status()?;
 
-        if total_earn < self.game.minimum_cost && current_time > self.game.opened_timestamp.checked_add(self.game.duration).ok_or(WinError::NumericalOverflow)? {
+        if self.game.status == GameStatus::Cancelled {
             // authority bump seed
             let (_pda, bump_seed) = Pubkey::find_program_address(&[NFT_POOL.as_ref()], &crate::ID);
 
-            for (i, _ata) in remaining_accounts.iter().enumerate().step_by(2) {
-                if i % 2 == 0 {
-                    assert_is_ata(&remaining_accounts[i], &self.organizer_wallet.key(), &self.game.nft_mint_address[i/2])?;
-                    assert_is_ata(&remaining_accounts[i+1], &self.nft_pool.key(), &self.game.nft_mint_address[i/2])?;
+            // transfer NFT or token prize back to organizer
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.nft_pool_ata.to_account_info(),
+                        to: self.organizer_nft_ata.to_account_info(),
+                        authority: self.nft_pool.to_account_info(),
+                    },
+                    &[&[NFT_POOL.as_ref(), &[bump_seed]]],
+                ),
+                self.game_prize.amount,
+            )?;
 
-                    // transfer NFT to NFT-Pool
-                    token::transfer(
-                        CpiContext::new_with_signer(
-                            self.token_program.to_account_info(),
-                            Transfer {
-                                from: remaining_accounts[i+1].to_account_info(),
-                                to: remaining_accounts[i].to_account_info(),
-                                authority: self.nft_pool.to_account_info(),
-                            },
-                            &[&[NFT_POOL.as_ref(), &[bump_seed]]],
-                        ),
-                        1,
-                    )?;
-                }
+            self.game_prize.is_claimed = true;
+            self.game.claimed_prize_count = self.game.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+            if self.game.claimed_prize_count == self.game.prize_count {
+                self.game.is_nft_unstaked = true;
             }
-
-            self.game.is_nft_unstaked = true;
         } else {
             return Err(error!(WinError::GameIsNotCancelledStatus));
         }
@@ -80,4 +104,3 @@ impl<'info> OrganizerGetBackNft<'info> {
         Ok(())
     }
 }
-
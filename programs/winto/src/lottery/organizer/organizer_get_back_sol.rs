@@ -0,0 +1,62 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// organizer get back SOL prize
+#[derive(Accounts)]
+pub struct OrganizerGetBackSol<'info> {
+    /// organizer
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.is_nft_unstaked == false @ WinError::NftAlreadyClaimed,
+        constraint = game.nft_owner_wallet == organizer_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda
+    #[account(
+        mut,
+        close = organizer_wallet,
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game_prize.index.to_le_bytes()],
+        bump = game_prize.bump,
+        constraint = game_prize.is_claimed == false @ WinError::NftAlreadyClaimed,
+        constraint = game_prize.prize_type == PrizeType::Sol @ WinError::InvalidPrizeType
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> OrganizerGetBackSol<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        self.game.update_status()?;
+
+        if self.game.status == GameStatus::Cancelled {
+            // closing the game prize pda returns the prize lamports with its rent
+            self.game_prize.is_claimed = true;
+            self.game.claimed_prize_count = self.game.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+            if self.game.claimed_prize_count == self.game.prize_count {
+                self.game.is_nft_unstaked = true;
+            }
+        } else {
+            return Err(error!(WinError::GameIsNotCancelledStatus));
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::globals::*;
-use anchor_lang::{prelude::*, solana_program::clock};
+use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 /// organizer claim or move to escrow game money(any token)
@@ -34,8 +34,7 @@ pub struct OrganizerProcessGameToken<'info> {
     /// Community PDA
     /// CHECK: Safe account
     #[account(
-        seeds = [COMMUNITY.as_ref()],
-        bump
+        constraint = vault_token_account.key() == game.fee_recipient @ WinError::InvalidAccount
     )]
     pub vault_token_account: UncheckedAccount<'info>,
 
@@ -62,6 +61,11 @@ pub struct OrganizerProcessGameToken<'info> {
     )]
     pub game_pool: UncheckedAccount<'info>,
 
+    /// jackpot pda of the game payment mint; may not be created yet
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub jackpot: UncheckedAccount<'info>,
+
     /// Win details PDA
     #[account(
         seeds = [WIN.as_ref()],
@@ -76,31 +80,31 @@ pub struct OrganizerProcessGameToken<'info> {
 
 impl<'info> OrganizerProcessGameToken<'info> {
     pub fn process(&mut self) -> Result<()> {
-        let total_earn = (self.game.ticket_price as u128).checked_mul(self.game.current_total_tickets as u128).ok_or(WinError::NumericalOverflow)? as u64;
+        let total_earn = self.game.total_earn()?;
         if total_earn > self.game_token_pool.amount {
             return Err(error!(WinError::InsufficientTokenBalance));
         }
-        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
-        
-        if total_earn >= self.game.minimum_cost && current_time > self.game.opened_timestamp.checked_add(self.game.duration).ok_or(WinError::NumericalOverflow)? {
-
-            let commision_fee = match self.game.wings_type {
-                WingsType::Gold => {
-                    self.win.community_fee.checked_mul(7).ok_or(WinError::NumericalOverflow)?
-                }
-                WingsType::Silver => {
-                    self.win.community_fee.checked_mul(8).ok_or(WinError::NumericalOverflow)?
-                }
-                WingsType::Bronze => {
-                    self.win.community_fee.checked_mul(9).ok_or(WinError::NumericalOverflow)?
-                }
-                WingsType::None => {
-                    self.win.community_fee.checked_mul(10).ok_or(WinError::NumericalOverflow)?
-                }
+        self.game.update_status()?;
+
+        // only a revealed draw is final; an unrevealed one can still be cancelled and refunded
+        if self.game.status == GameStatus::Settled {
+
+            // settle against the fee terms snapshotted at game creation
+            let community_fee = (total_earn as u128).checked_mul(self.game.fee_bps as u128).ok_or(WinError::NumericalOverflow)?.checked_div(10000 as u128).ok_or(WinError::NumericalOverflow)? as u64;
+            // jackpot slice stays in the game pool and is paid out by the jackpot draw;
+            // without a jackpot for the payment mint the slice goes to the organizer
+            let jackpot = if self.game.jackpot_fee_bps > 0 {
+                load_jackpot(&self.jackpot, self.game.payment_mint())?
+            } else {
+                None
             };
-            
-            let community_fee = (total_earn as u128).checked_mul(commision_fee as u128).ok_or(WinError::NumericalOverflow)?.checked_div(10000 as u128).ok_or(WinError::NumericalOverflow)? as u64;
-            let organizer_fee = total_earn.checked_sub(community_fee).ok_or(WinError::NumericalOverflow)?;
+            let jackpot_fee_bps = if jackpot.is_some() { self.game.jackpot_fee_bps } else { 0 };
+            let jackpot_fee = (total_earn as u128).checked_mul(jackpot_fee_bps as u128).ok_or(WinError::NumericalOverflow)?.checked_div(10000 as u128).ok_or(WinError::NumericalOverflow)? as u64;
+            let organizer_fee = total_earn.checked_sub(community_fee).ok_or(WinError::NumericalOverflow)?.checked_sub(jackpot_fee).ok_or(WinError::NumericalOverflow)?;
+            if let Some(mut jackpot) = jackpot {
+                jackpot.balance = jackpot.balance.checked_add(jackpot_fee).ok_or(WinError::NumericalOverflow)?;
+                jackpot.exit(&crate::ID)?;
+            }
             
             // authority bump seed
             let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
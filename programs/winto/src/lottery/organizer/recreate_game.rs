@@ -19,7 +19,7 @@ pub struct RecreateGame<'info> {
     /// game pda
     #[account(
         init,
-        seeds = [GAME.as_ref(), &args.game_time_stamp.to_le_bytes(), &organizer_wallet.key().to_bytes()],
+        seeds = [GAME.as_ref(), &args.game_nonce.to_le_bytes(), &organizer_wallet.key().to_bytes()],
         bump,
         payer = organizer_wallet,
         space = 8 + size_of::<Game>(),
@@ -30,7 +30,8 @@ pub struct RecreateGame<'info> {
     #[account(
         mut,
         constraint = old_game.nft_owner_wallet == organizer_wallet.key() @ WinError::IncorrectOwner,
-        constraint = old_game.is_nft_unstaked == false @ WinError::NoGameNft
+        constraint = old_game.is_nft_unstaked == false @ WinError::NoGameNft,
+        constraint = old_game.claimed_prize_count == 0 @ WinError::NoGameNft
     )]
     pub old_game: Box<Account<'info, Game>>,
 
@@ -44,43 +45,107 @@ pub struct RecreateGame<'info> {
 
     /// user details pda
     #[account(
+        mut,
         seeds = [USER_DETAILS.as_ref(), &organizer_wallet.key().to_bytes()],
         bump = user_details.bump,
         constraint = user_details.user_wallet == organizer_wallet.key() @ WinError::AccessDenied,
-        constraint = user_details.verify_create_game(args.proof, organizer_wallet.key(), win.organizer_whitelist_merkle_root, args.wings_type) @ WinError::UnableToCreateGame
+        constraint = user_details.verify_create_game(args.proof, organizer_wallet.key(), win.organizer_whitelist_merkle_root, args.wings_nft_mint_address) @ WinError::UnableToCreateGame
     )]
     pub user_details: Box<Account<'info, UserDetails>>,
 
+    /// User Wings NFT locked pda; unused without a Wings NFT
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub user_wings_nft_locked: UncheckedAccount<'info>,
+
     /// system program
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> RecreateGame<'info> {
     pub fn process(&mut self, bump: u8, args: RecreateGameArgs) -> Result<()> {
-        let total_earn = (self.old_game.ticket_price as u128).checked_mul(self.old_game.current_total_tickets as u128).ok_or(WinError::NumericalOverflow)? as u64;
         let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        self.old_game.update_status()?;
+        if args.duration == 0 {
+            return Err(error!(WinError::InvalidArgs));
+        }
+        verify_game_start_time(args.start_time, current_time)?;
+        let ticket_price = args.ticket_price.unwrap_or(self.old_game.ticket_price);
+        let minimum_cost = args.minimum_cost.unwrap_or(self.old_game.minimum_cost);
+
+        // the payment mint only makes sense together with its coin type
+        if args.coin_type.is_some() != args.ticket_token_address.is_some() {
+            return Err(error!(WinError::InvalidArgs));
+        }
 
-        if total_earn < self.old_game.minimum_cost && current_time > self.old_game.opened_timestamp.checked_add(self.old_game.duration).ok_or(WinError::NumericalOverflow)? {
+        if self.old_game.status == GameStatus::Cancelled {
             self.game.nft_owner_wallet = self.organizer_wallet.key();
-            self.game.ticket_token_address = self.old_game.ticket_token_address;
-            self.game.ticket_price = self.old_game.ticket_price;
-            self.game.minimum_cost = self.old_game.minimum_cost;
-            self.game.opened_timestamp = args.game_time_stamp;
+            self.game.ticket_token_address = args.ticket_token_address.unwrap_or(self.old_game.ticket_token_address);
+            self.game.ticket_price = ticket_price;
+            self.game.minimum_cost = minimum_cost;
+            self.game.opened_timestamp = args.start_time;
             self.game.duration = args.duration;
             self.game.current_total_tickets = 0;
             self.game.current_total_bonus_tickets = 0;
-            self.game.coin_type = self.old_game.coin_type.clone();
+            self.game.coin_type = args.coin_type.clone().unwrap_or(self.old_game.coin_type.clone());
             self.game.is_nft_unstaked = false;
             self.game.funds_status = FundsStatus::NotClaimed;
-            self.game.wings_type = args.wings_type;
+            self.game.wings_type = attach_wings_nft(
+                self.organizer_wallet.key(),
+                args.wings_nft_mint_address,
+                &self.user_wings_nft_locked,
+            )?;
             self.game.wings_nft_mint_address = args.wings_nft_mint_address;
+            self.game.is_released = false;
+            self.game.is_cancel_final = false;
+            self.game.closed_prize_count = 0;
+            self.game.entrant_count = 0;
+            self.game.registered_entrant_count = 0;
+            self.game.registered_entries = 0;
+            self.game.winner_entries = 0;
+            self.game.drawing_started_at = 0;
+            let wings_tier = self.win.wings_tier(&self.game.wings_type);
+            self.user_details.open_game(&wings_tier, args.duration)?;
+            self.user_details.use_game_nonce(args.game_nonce)?;
+            self.game.snapshot_fee_terms(&self.win)?;
             self.game.bump = bump;
-            self.game.nft_mint_address = self.old_game.nft_mint_address.clone();
-            self.game.winner_nft_count = self.old_game.winner_nft_count;
-            self.game.winner_random_number = [0;6];
+            self.game.prize_count = self.old_game.prize_count;
+            // the tier may have been lowered since the prizes were added
+            self.game.verify_prize_limit(wings_tier.max_prizes, 0)?;
+            self.game.claimed_prize_count = 0;
+            self.game.prize_game = self.old_game.prize_game;
+            self.game.winner_mode = args.winner_mode.clone().unwrap_or(self.old_game.winner_mode.clone());
+            self.game.total_bidders = 0;
+            self.game.total_entries = 0;
+            self.game.extended_duration = 0;
+            self.game.refund_window_end = 0;
+            self.game.max_tickets = self.old_game.max_tickets;
+            self.game.max_tickets_per_wallet = self.old_game.max_tickets_per_wallet;
+            self.game.anti_snipe_window = self.old_game.anti_snipe_window;
+            self.game.anti_snipe_extension = self.old_game.anti_snipe_extension;
+            self.game.anti_snipe_max_extension = self.old_game.anti_snipe_max_extension;
+            self.game.anti_snipe_extended = 0;
+            self.game.bid_account_count = 0;
+            self.game.previous_game = self.old_game.key();
+            self.game.pricing_mode = self.old_game.pricing_mode.clone();
+            self.game.price_increment = self.old_game.price_increment;
+            self.game.price_step_size = self.old_game.price_step_size;
+            self.game.floor_price = self.old_game.floor_price;
+            self.game.total_paid = 0;
+            self.game.bundle_tiers = self.old_game.bundle_tiers;
+            self.game.verify_payment()?;
+            self.game.verify_time_bounds()?;
+            self.game.verify_pricing()?;
+            self.game.draw_commitment = args.draw_commitment;
+            self.game.status = if args.start_time > current_time {
+                GameStatus::Scheduled
+            } else {
+                GameStatus::Open
+            };
 
             self.old_game.is_nft_unstaked = true;
             self.old_game.funds_status = FundsStatus::Retransfer;
+            self.old_game.set_status(GameStatus::Recreated)?;
         } else {
             return Err(error!(WinError::GameIsNotCancelledStatus));
         }
@@ -94,15 +159,33 @@ pub struct RecreateGameArgs {
     /// proof
     pub proof: Vec<[u8; 32]>,
     
-    /// game time stamp
-    pub game_time_stamp: u32,
+    /// game nonce for pda seed
+    pub game_nonce: u32,
 
-    /// wings type
-    pub wings_type: WingsType,
+    /// game start time
+    pub start_time: u32,
 
     /// Wings NFT mint address
     pub wings_nft_mint_address: Pubkey,
 
     /// duration
-    pub duration: u32
+    pub duration: u32,
+
+    /// bot draw commitment
+    pub draw_commitment: [u8;32],
+
+    /// new ticket price
+    pub ticket_price: Option<u64>,
+
+    /// new minimum cost
+    pub minimum_cost: Option<u64>,
+
+    /// new coin type; set together with ticket_token_address
+    pub coin_type: Option<CoinType>,
+
+    /// new token address for buy ticket; set together with coin_type
+    pub ticket_token_address: Option<Pubkey>,
+
+    /// new winner mode
+    pub winner_mode: Option<WinnerMode>,
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+
+/// organizer claim game money(SOL) of a game in the old layout that reached its minimum cost
+#[derive(Accounts)]
+pub struct SettleLegacyGameSol<'info> {
+    /// organizer wallet
+    #[account(mut)]
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda in the old layout
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub game: UncheckedAccount<'info>,
+
+    /// Game SOL Pool
+    /// CHECK: Safe account
+    #[account(
+        mut,
+        seeds = [GAME_POOL.as_ref()],
+        bump,
+    )]
+    pub game_sol_pool: UncheckedAccount<'info>,
+
+    /// Community wallet
+    /// CHECK: Safe account
+    #[account(
+        mut,
+        seeds = [COMMUNITY.as_ref()],
+        bump
+    )]
+    pub coummunity_account: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> SettleLegacyGameSol<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let mut game = LegacyGame::load(&self.game)?;
+        if game.nft_owner_wallet != self.organizer_wallet.key() {
+            return Err(error!(WinError::AccessDenied));
+        }
+        if game.funds_status != FundsStatus::NotClaimed {
+            return Err(error!(WinError::ClaimedAlready));
+        }
+        if game.coin_type != CoinType::SOL {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        if !game.is_ended()? || !game.is_sold()? {
+            return Err(error!(WinError::GameIsNotClosedStatus));
+        }
+
+        let total_earn = game.total_earn()?;
+        if **self.game_sol_pool.lamports.borrow() < total_earn {
+            return Err(error!(WinError::InsufficientSolBalance));
+        }
+        // old games pay the community fee of their Wings tier and no jackpot slice
+        let fee_bps = self.win.wings_tier(&game.wings_type).fee_bps;
+        let community_fee = (total_earn as u128).checked_mul(fee_bps as u128).ok_or(WinError::NumericalOverflow)?.checked_div(10000 as u128).ok_or(WinError::NumericalOverflow)? as u64;
+        let organizer_fee = total_earn.checked_sub(community_fee).ok_or(WinError::NumericalOverflow)?;
+
+        // authority bump seed
+        let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
+
+        invoke_signed(
+            &system_instruction::transfer(
+                self.game_sol_pool.key,
+                self.coummunity_account.key,
+                community_fee,
+            ),
+            &[
+                self.game_sol_pool.to_account_info().clone(),
+                self.coummunity_account.to_account_info().clone(),
+                self.system_program.to_account_info().clone(),
+            ],
+            &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+        )?;
+
+        invoke_signed(
+            &system_instruction::transfer(
+                self.game_sol_pool.key,
+                self.organizer_wallet.key,
+                organizer_fee,
+            ),
+            &[
+                self.game_sol_pool.to_account_info().clone(),
+                self.organizer_wallet.to_account_info().clone(),
+                self.system_program.to_account_info().clone(),
+            ],
+            &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+        )?;
+
+        game.funds_status = FundsStatus::Withdrawed;
+        game.save(&self.game)?;
+
+        Ok(())
+    }
+}
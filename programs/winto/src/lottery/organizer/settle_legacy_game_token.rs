@@ -0,0 +1,134 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// organizer claim game money(any token) of a game in the old layout that reached its minimum cost
+#[derive(Accounts)]
+pub struct SettleLegacyGameToken<'info> {
+    /// organizer wallet
+    pub organizer_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// User token account
+    #[account(
+        mut,
+        constraint = organizer_token_ata.owner == organizer_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub organizer_token_ata: Box<Account<'info, TokenAccount>>,
+
+    /// game pda in the old layout
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub game: UncheckedAccount<'info>,
+
+    /// Community PDA
+    /// CHECK: Safe account
+    #[account(
+        seeds = [COMMUNITY.as_ref()],
+        bump
+    )]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    /// admin wallet token account
+    #[account(
+        mut,
+        constraint = receive_token_ata.owner == vault_token_account.key() @ WinError::InvalidTokenOwner,
+    )]
+    pub receive_token_ata: Box<Account<'info, TokenAccount>>,
+
+    /// Game Token Pool
+    #[account(
+        mut,
+        constraint = game_token_pool.owner == game_pool.key() @ WinError::AccessDenied,
+    )]
+    pub game_token_pool: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Safe account
+    #[account(
+        seeds = [GAME_POOL.as_ref()],
+        bump,
+    )]
+    pub game_pool: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SettleLegacyGameToken<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let mut game = LegacyGame::load(&self.game)?;
+        if game.nft_owner_wallet != self.organizer_wallet.key() {
+            return Err(error!(WinError::AccessDenied));
+        }
+        if game.funds_status != FundsStatus::NotClaimed {
+            return Err(error!(WinError::ClaimedAlready));
+        }
+        if game.coin_type != CoinType::TOKEN {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        if self.organizer_token_ata.mint != game.ticket_token_address ||
+            self.receive_token_ata.mint != game.ticket_token_address ||
+            self.game_token_pool.mint != game.ticket_token_address {
+            return Err(error!(WinError::InvalidTokenMint));
+        }
+        if !game.is_ended()? || !game.is_sold()? {
+            return Err(error!(WinError::GameIsNotClosedStatus));
+        }
+
+        let total_earn = game.total_earn()?;
+        if total_earn > self.game_token_pool.amount {
+            return Err(error!(WinError::InsufficientTokenBalance));
+        }
+        // old games pay the community fee of their Wings tier and no jackpot slice
+        let fee_bps = self.win.wings_tier(&game.wings_type).fee_bps;
+        let community_fee = (total_earn as u128).checked_mul(fee_bps as u128).ok_or(WinError::NumericalOverflow)?.checked_div(10000 as u128).ok_or(WinError::NumericalOverflow)? as u64;
+        let organizer_fee = total_earn.checked_sub(community_fee).ok_or(WinError::NumericalOverflow)?;
+
+        // authority bump seed
+        let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.game_token_pool.to_account_info(),
+                    to: self.receive_token_ata.to_account_info(),
+                    authority: self.game_pool.to_account_info(),
+                },
+                &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+            ),
+            community_fee,
+        )?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.game_token_pool.to_account_info(),
+                    to: self.organizer_token_ata.to_account_info(),
+                    authority: self.game_pool.to_account_info(),
+                },
+                &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+            ),
+            organizer_fee,
+        )?;
+
+        game.funds_status = FundsStatus::Withdrawed;
+        game.save(&self.game)?;
+
+        Ok(())
+    }
+}
@@ -61,7 +61,8 @@ pub struct UnlockWingsNft<'info> {
         mut,
         seeds = [&organizer_wallet.key().to_bytes(), &mint_nft.key().to_bytes()],
         bump,
-        close = organizer_wallet
+        close = organizer_wallet,
+        constraint = user_wings_nft_locked.active_game_count == 0 @ WinError::WingsNftInUse
     )]
     pub user_wings_nft_locked: Box<Account<'info, UserWingsNftLocked>>,
 
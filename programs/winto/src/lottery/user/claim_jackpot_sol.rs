@@ -0,0 +1,85 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+
+/// jackpot winner claim SOL payout
+#[derive(Accounts)]
+pub struct ClaimJackpotSol<'info> {
+    /// winner
+    #[account(mut)]
+    pub winner_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// jackpot pda
+    #[account(
+        mut,
+        seeds = [JACKPOT.as_ref(), &Pubkey::default().to_bytes()],
+        bump = jackpot.bump,
+        constraint = jackpot.coin_type == CoinType::SOL @ WinError::InvalidAccount,
+        constraint = jackpot.pending_payout > 0 @ WinError::NoJackpotPayout
+    )]
+    pub jackpot: Box<Account<'info, Jackpot>>,
+
+    /// jackpot entry pda of the winner
+    #[account(
+        mut,
+        close = winner_wallet,
+        seeds = [JACKPOT_ENTRY.as_ref(), &jackpot.key().to_bytes(), &jackpot.payout_epoch.to_le_bytes(), &winner_wallet.key().to_bytes()],
+        bump = jackpot_entry.bump,
+        constraint = jackpot_entry.index == jackpot.winning_entry @ WinError::NotWinner
+    )]
+    pub jackpot_entry: Box<Account<'info, JackpotEntry>>,
+
+    /// Game SOL Pool
+    /// CHECK: Safe account
+    #[account(
+        mut,
+        seeds = [GAME_POOL.as_ref()],
+        bump,
+    )]
+    pub game_pool: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> ClaimJackpotSol<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let payout = self.jackpot.pending_payout;
+        if **self.game_pool.lamports.borrow() < payout {
+            return Err(error!(WinError::InsufficientGameMoney));
+        }
+        // authority bump seed
+        let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
+
+        invoke_signed(
+            &system_instruction::transfer(
+                self.game_pool.key,
+                self.winner_wallet.key,
+                payout,
+            ),
+            &[
+                self.game_pool.to_account_info().clone(),
+                self.winner_wallet.to_account_info().clone(),
+                self.system_program.to_account_info().clone(),
+            ],
+            &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+        )?;
+
+        self.jackpot.pending_payout = 0;
+        Ok(())
+    }
+}
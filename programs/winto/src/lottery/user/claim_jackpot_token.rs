@@ -0,0 +1,98 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// jackpot winner claim token payout
+#[derive(Accounts)]
+pub struct ClaimJackpotToken<'info> {
+    /// winner
+    #[account(mut)]
+    pub winner_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// winner token account
+    #[account(
+        mut,
+        constraint = winner_token_ata.mint == jackpot.mint @ WinError::InvalidTokenMint,
+        constraint = winner_token_ata.owner == winner_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub winner_token_ata: Box<Account<'info, TokenAccount>>,
+
+    /// jackpot pda
+    #[account(
+        mut,
+        seeds = [JACKPOT.as_ref(), &jackpot.mint.to_bytes()],
+        bump = jackpot.bump,
+        constraint = jackpot.coin_type == CoinType::TOKEN @ WinError::InvalidAccount,
+        constraint = jackpot.pending_payout > 0 @ WinError::NoJackpotPayout
+    )]
+    pub jackpot: Box<Account<'info, Jackpot>>,
+
+    /// jackpot entry pda of the winner
+    #[account(
+        mut,
+        close = winner_wallet,
+        seeds = [JACKPOT_ENTRY.as_ref(), &jackpot.key().to_bytes(), &jackpot.payout_epoch.to_le_bytes(), &winner_wallet.key().to_bytes()],
+        bump = jackpot_entry.bump,
+        constraint = jackpot_entry.index == jackpot.winning_entry @ WinError::NotWinner
+    )]
+    pub jackpot_entry: Box<Account<'info, JackpotEntry>>,
+
+    /// Game Token Pool
+    #[account(
+        mut,
+        constraint = game_token_pool.mint == jackpot.mint @ WinError::InvalidTokenMint,
+        constraint = game_token_pool.owner == game_pool.key() @ WinError::AccessDenied,
+    )]
+    pub game_token_pool: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Safe account
+    #[account(
+        seeds = [GAME_POOL.as_ref()],
+        bump,
+    )]
+    pub game_pool: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimJackpotToken<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let payout = self.jackpot.pending_payout;
+        if self.game_token_pool.amount < payout {
+            return Err(error!(WinError::InsufficientTokenBalance));
+        }
+        // authority bump seed
+        let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.game_token_pool.to_account_info(),
+                    to: self.winner_token_ata.to_account_info(),
+                    authority: self.game_pool.to_account_info(),
+                },
+                &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+            ),
+            payout,
+        )?;
+
+        self.jackpot.pending_payout = 0;
+        Ok(())
+    }
+}
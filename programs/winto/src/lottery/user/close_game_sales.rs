@@ -0,0 +1,31 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// anyone persists the status of an ended game: closing sales into drawing or cancelled,
+/// fixing a new draw slot once the old one aged out and cancelling an unrevealed draw
+#[derive(Accounts)]
+pub struct CloseGameSales<'info> {
+    /// game pda
+    #[account(mut)]
+    pub game: Box<Account<'info, Game>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> CloseGameSales<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let status = self.game.status.clone();
+        let draw_slot = self.game.draw_slot;
+        self.game.update_status()?;
+        if self.game.status == status && self.game.draw_slot == draw_slot {
+            return Err(error!(WinError::InvalidGameStatus));
+        }
+        Ok(())
+    }
+}
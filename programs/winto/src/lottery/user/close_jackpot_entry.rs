@@ -0,0 +1,47 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// user close a jackpot entry of a drawn epoch and reclaim rent
+#[derive(Accounts)]
+pub struct CloseJackpotEntry<'info> {
+    /// user wallet
+    #[account(mut)]
+    pub user_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// jackpot pda
+    #[account(
+        seeds = [JACKPOT.as_ref(), &jackpot.mint.to_bytes()],
+        bump = jackpot.bump,
+    )]
+    pub jackpot: Box<Account<'info, Jackpot>>,
+
+    /// jackpot entry pda
+    #[account(
+        mut,
+        close = user_wallet,
+        seeds = [JACKPOT_ENTRY.as_ref(), &jackpot.key().to_bytes(), &jackpot_entry.epoch.to_le_bytes(), &user_wallet.key().to_bytes()],
+        bump = jackpot_entry.bump,
+        constraint = jackpot.is_entry_closable(&jackpot_entry) @ WinError::AccountInUse
+    )]
+    pub jackpot_entry: Box<Account<'info, JackpotEntry>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> CloseJackpotEntry<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
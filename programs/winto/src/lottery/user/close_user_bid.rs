@@ -0,0 +1,70 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// user close a bid PDA that no longer matters and reclaim rent
+#[derive(Accounts)]
+pub struct CloseUserBid<'info> {
+    /// user wallet
+    #[account(mut)]
+    pub user_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// user bid PDA
+    #[account(
+        mut,
+        close = user_wallet,
+        seeds = [USER_BID.as_ref(), user_bid.bid_number.to_string().as_ref(), &user_wallet.key().to_bytes(), &game.key().to_bytes()],
+        bump,
+        constraint = user_bid.user_wallet == user_wallet.key() @ WinError::AccessDenied,
+        constraint = user_bid.game_pda_address == game.key() @ WinError::InvalidAccount
+    )]
+    pub user_bid: Box<Account<'info, UserBid>>,
+
+    /// CHECK: game pda; may already be closed by the organizer
+    #[account(mut)]
+    pub game: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> CloseUserBid<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // the organizer closed the game after the bid close period; nothing is left to settle
+        if self.game.data_is_empty() {
+            return Ok(());
+        }
+
+        let mut game: Account<Game> = Account::try_from(&self.game)?;
+        game.update_status()?;
+        if !game.are_bids_final(self.win.game_extension_grace_period)? {
+            return Err(error!(WinError::AccountInUse));
+        }
+
+        // global bid carries refunds and prize claims
+        if self.user_bid.bid_number == 1 {
+            let is_refund_pending = self.user_bid.funds_status == FundsStatus::NotClaimed && self.user_bid.gbl_total_ticket_amount > 0;
+            if game.status != GameStatus::Settled && is_refund_pending {
+                return Err(error!(WinError::AccountInUse));
+            }
+            if self.user_bid.is_winner && self.user_bid.claimed_prize_count < game.winner_prize_count(self.user_bid.winner_slot) {
+                return Err(error!(WinError::AccountInUse));
+            }
+        }
+
+        game.bid_account_count = game.bid_account_count.checked_sub(1).ok_or(WinError::NumericalOverflow)?;
+        game.exit(&crate::ID)?;
+
+        Ok(())
+    }
+}
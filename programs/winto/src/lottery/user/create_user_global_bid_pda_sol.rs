@@ -28,10 +28,7 @@ pub struct CreateUserGlobalBidPdaSol<'info> {
     pub user_global_bid: Box<Account<'info, UserBid>>,
 
     /// game pda
-    #[account(
-        mut,
-        constraint = game.verify_game_time() @ WinError::GameIsNotOpenedStatus
-    )]
+    #[account(mut)]
     pub game: Box<Account<'info, Game>>,
 
     /// Game SOL Pool
@@ -65,9 +62,20 @@ pub struct CreateUserGlobalBidPdaSol<'info> {
 
 impl<'info> CreateUserGlobalBidPdaSol<'info> {
     pub fn process(&mut self, bump: u8, args: CreateUserGlobalBidPdaSolArgs) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status == GameStatus::Scheduled {
+            return Err(error!(WinError::GameIsNotStarted));
+        }
+        if self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
+        if self.game.prize_count == 0 {
+            return Err(error!(WinError::NoGamePrize));
+        }
         if args.ticket_amount.checked_add(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?  == 0 {
             return Err(error!(WinError::NoTicketAmount));
         }
+        self.game.verify_ticket_limits(0, args.ticket_amount, args.bonus_ticket_amount)?;
 
         // current time, unix timestamp
         let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
@@ -81,7 +89,7 @@ impl<'info> CreateUserGlobalBidPdaSol<'info> {
             return Err(error!(WinError::InvalidBonusTicketAmount));
         }
 
-        let ticket_total_price = (args.ticket_amount as u128).checked_mul(self.game.ticket_price as u128).ok_or(WinError::NumericalOverflow)? as u64;
+        let ticket_total_price = self.game.purchase_cost(args.ticket_amount)?;
         if **self.user_wallet.lamports.borrow() < ticket_total_price {
             return Err(error!(WinError::InsufficientSolBalance));
         }
@@ -93,15 +101,25 @@ impl<'info> CreateUserGlobalBidPdaSol<'info> {
             self.user_global_bid.gbl_has_distirbuted_bonus = false;
         }
         
+        self.user_global_bid.ticket_start_index = self.game.total_entries;
+        self.game.total_entries = self.game.total_entries.checked_add(args.ticket_amount).ok_or(WinError::NumericalOverflow)?.checked_add(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?;
+        self.game.total_bidders = self.game.total_bidders.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.entrant_index = self.game.entrant_count;
+        self.user_global_bid.entry_start_index = 0;
+        self.game.entrant_count = self.game.entrant_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.game.bid_account_count = self.game.bid_account_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
         self.game.current_total_tickets = self.game.current_total_tickets.checked_add(args.ticket_amount).ok_or(WinError::NumericalOverflow)?;
         self.game.current_total_bonus_tickets = self.game.current_total_bonus_tickets.checked_add(args.bonus_ticket_amount as u32).ok_or(WinError::NumericalOverflow)?;
-        self.game.winner_random_number = args.random_number;
 
         self.user_global_bid.user_wallet = self.user_wallet.key();
         self.user_global_bid.game_pda_address = self.game.key();
         self.user_global_bid.opened_timestamp = current_time;
         self.user_global_bid.gbl_total_ticket_amount = args.ticket_amount;
+        self.user_global_bid.gbl_entry_amount = args.ticket_amount.checked_add(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?;
         self.user_global_bid.bought_ticket_amount = args.ticket_amount;
+        self.user_global_bid.bought_amount = ticket_total_price;
+        self.user_global_bid.gbl_paid_amount = ticket_total_price;
+        self.game.total_paid = self.game.total_paid.checked_add(ticket_total_price).ok_or(WinError::NumericalOverflow)?;
         self.user_global_bid.bonus_ticket_amount = 0;
         self.user_global_bid.freely_ticket_amount = args.bonus_ticket_amount;
         self.user_details.freely_ticket_amount = self.user_details.freely_ticket_amount.checked_sub(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?;
@@ -110,6 +128,7 @@ impl<'info> CreateUserGlobalBidPdaSol<'info> {
         self.user_global_bid.bid_number = 1;
         self.user_global_bid.funds_status = FundsStatus::NotClaimed;
         self.user_global_bid.winner_nft_claim = false;
+        self.user_global_bid.is_winner = false;
         self.user_global_bid.bump = bump;
         
 
@@ -127,8 +146,9 @@ impl<'info> CreateUserGlobalBidPdaSol<'info> {
                 ],
             )?;
         }
-            
 
+        self.game.apply_anti_snipe()?;
+        self.game.close_if_sold_out()?;
         Ok(())
     }
 }
@@ -140,7 +160,4 @@ pub struct CreateUserGlobalBidPdaSolArgs {
 
     /// bonus ticket amount,
     pub bonus_ticket_amount: u32,
-
-    /// winner random number
-    pub random_number: [u32;6],
 }
@@ -0,0 +1,88 @@
+use crate::globals::*;
+use anchor_lang::{prelude::*, solana_program::clock};
+use std::mem::size_of;
+
+/// user enter the jackpot of the current epoch with a bid bought in it; only settled games pay into the jackpot
+#[derive(Accounts)]
+pub struct EnterJackpot<'info> {
+    /// user wallet
+    #[account(mut)]
+    pub user_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        constraint = game.status == GameStatus::Settled @ WinError::NotDrawnYet
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// user bid PDA bought in the current epoch
+    #[account(
+        seeds = [USER_BID.as_ref(), user_bid.bid_number.to_string().as_ref(), &user_wallet.key().to_bytes(), &game.key().to_bytes()],
+        bump,
+        constraint = user_bid.user_wallet == user_wallet.key() @ WinError::AccessDenied,
+        constraint = user_bid.game_pda_address == game.key() @ WinError::InvalidAccount,
+        constraint = user_bid.bought_ticket_amount > 0 @ WinError::NoTicketAmount,
+        constraint = user_bid.opened_timestamp >= jackpot.epoch_start @ WinError::JackpotEpochEnded
+    )]
+    pub user_bid: Box<Account<'info, UserBid>>,
+
+    /// global bid PDA; refunded wallets paid nothing into the jackpot
+    #[account(
+        seeds = [USER_BID.as_ref(), b"1".as_ref(), &user_wallet.key().to_bytes(), &game.key().to_bytes()],
+        bump = user_global_bid.bump,
+        constraint = user_global_bid.funds_status == FundsStatus::NotClaimed @ WinError::ClaimedAlready
+    )]
+    pub user_global_bid: Box<Account<'info, UserBid>>,
+
+    /// jackpot pda of the game payment mint
+    #[account(
+        mut,
+        seeds = [JACKPOT.as_ref(), &game.payment_mint().to_bytes()],
+        bump = jackpot.bump,
+    )]
+    pub jackpot: Box<Account<'info, Jackpot>>,
+
+    /// jackpot entry pda
+    #[account(
+        init,
+        seeds = [JACKPOT_ENTRY.as_ref(), &jackpot.key().to_bytes(), &jackpot.epoch.to_le_bytes(), &user_wallet.key().to_bytes()],
+        bump,
+        payer = user_wallet,
+        space = 8 + size_of::<JackpotEntry>(),
+    )]
+    pub jackpot_entry: Box<Account<'info, JackpotEntry>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EnterJackpot<'info> {
+    pub fn process(&mut self, bump: u8) -> Result<()> {
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+        if current_time >= self.jackpot.epoch_start.checked_add(self.win.jackpot_epoch_duration).ok_or(WinError::NumericalOverflow)? {
+            return Err(error!(WinError::JackpotEpochEnded));
+        }
+
+        self.jackpot_entry.user_wallet = self.user_wallet.key();
+        self.jackpot_entry.jackpot = self.jackpot.key();
+        self.jackpot_entry.epoch = self.jackpot.epoch;
+        self.jackpot_entry.index = self.jackpot.entry_count;
+        self.jackpot_entry.bump = bump;
+        self.jackpot.entry_count = self.jackpot.entry_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        Ok(())
+    }
+}
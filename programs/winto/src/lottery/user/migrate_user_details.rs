@@ -0,0 +1,50 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use std::mem::size_of;
+
+/// migrate user details PDA created before the organizer game count
+#[derive(Accounts)]
+pub struct MigrateUserDetails<'info> {
+    /// user wallet
+    #[account(mut)]
+    pub user_wallet: Signer<'info>,
+
+    /// UserDetails PDA in the old layout
+    /// CHECK: verified in process
+    #[account(
+        mut,
+        seeds = [USER_DETAILS.as_ref(), &user_wallet.key().to_bytes()],
+        bump,
+    )]
+    pub user_details: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateUserDetails<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        migrate_account::<UserDetails>(
+            &self.user_details,
+            &self.user_wallet,
+            &self.system_program,
+            8 + size_of::<UserDetails>(),
+        )?;
+
+        let mut user_details: Account<UserDetails> = Account::try_from(&self.user_details)?;
+        if user_details.user_wallet != self.user_wallet.key() {
+            return Err(error!(WinError::AccessDenied));
+        }
+        user_details.active_game_count = 0;
+        user_details.exit(&crate::ID)?;
+        Ok(())
+    }
+}
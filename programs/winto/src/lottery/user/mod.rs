@@ -2,22 +2,46 @@ pub mod user_claim_airdrop_win;
 pub mod user_game_bid_sol;
 pub mod user_game_bid_token;
 pub mod winner_claim_nft;
+pub mod winner_claim_token;
+pub mod winner_claim_sol;
 pub mod create_user_details_by_user;
+pub mod migrate_user_details;
 pub mod create_user_global_bid_pda_sol;
 pub mod create_user_global_bid_pda_token;
 pub mod user_withdraw_funds_sol;
 pub mod user_withdraw_funds_token;
+pub mod refund_legacy_bid_sol;
+pub mod refund_legacy_bid_token;
 pub mod stake_freely_nft;
 pub mod unstake_freely_nft;
+pub mod close_user_bid;
+pub mod roll_bid_into_game;
+pub mod enter_jackpot;
+pub mod claim_jackpot_sol;
+pub mod claim_jackpot_token;
+pub mod close_jackpot_entry;
+pub mod close_game_sales;
 
 pub use user_claim_airdrop_win::*;
 pub use user_game_bid_sol::*;
 pub use user_game_bid_token::*;
 pub use winner_claim_nft::*;
+pub use winner_claim_token::*;
+pub use winner_claim_sol::*;
 pub use create_user_details_by_user::*;
+pub use migrate_user_details::*;
 pub use create_user_global_bid_pda_sol::*;
 pub use create_user_global_bid_pda_token::*;
 pub use user_withdraw_funds_sol::*;
 pub use user_withdraw_funds_token::*;
+pub use refund_legacy_bid_sol::*;
+pub use refund_legacy_bid_token::*;
 pub use stake_freely_nft::*;
-pub use unstake_freely_nft::*;
\ No newline at end of file
+pub use unstake_freely_nft::*;
+pub use close_user_bid::*;
+pub use roll_bid_into_game::*;
+pub use enter_jackpot::*;
+pub use claim_jackpot_sol::*;
+pub use claim_jackpot_token::*;
+pub use close_jackpot_entry::*;
+pub use close_game_sales::*;
\ No newline at end of file
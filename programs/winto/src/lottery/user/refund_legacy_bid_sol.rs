@@ -0,0 +1,98 @@
+use crate::globals::*;
+use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+use anchor_lang::prelude::*;
+
+/// user close a bid PDA of a game in the old layout, refunding the ticket money(SOL) of a cancelled game
+#[derive(Accounts)]
+pub struct RefundLegacyBidSol<'info> {
+    /// user wallet
+    #[account(mut)]
+    pub user_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// user bid PDA in the old layout
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub user_bid: UncheckedAccount<'info>,
+
+    /// game pda in the old layout
+    /// CHECK: verified in process
+    pub game: UncheckedAccount<'info>,
+
+    /// Game Pool
+    /// CHECK: Safe account
+    #[account(
+        mut,
+        seeds = [GAME_POOL.as_ref()],
+        bump,
+    )]
+    pub game_pool: UncheckedAccount<'info>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>
+}
+
+impl<'info> RefundLegacyBidSol<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let user_bid = LegacyUserBid::load(&self.user_bid, self.user_wallet.key(), self.game.key())?;
+        let game = LegacyGame::load(&self.game)?;
+        if game.coin_type != CoinType::SOL {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        if !game.is_ended()? {
+            return Err(error!(WinError::GameIsNotCancelledStatus));
+        }
+
+        // a winner's global bid vouches for the prize return until every prize left the pool
+        if user_bid.bid_number == 1 && game.is_sold()? && !game.are_prizes_returned() {
+            return Err(error!(WinError::AccountInUse));
+        }
+
+        // global bid of a game that missed its minimum cost carries the refund
+        let is_refund = user_bid.bid_number == 1 &&
+            !game.is_sold()? &&
+            game.funds_status == FundsStatus::NotClaimed &&
+            user_bid.funds_status == FundsStatus::NotClaimed;
+        if is_refund && user_bid.gbl_total_ticket_amount > 0 {
+            let withdraw_funds = (user_bid.gbl_total_ticket_amount as u128).checked_mul(game.ticket_price as u128).ok_or(WinError::NumericalOverflow)? as u64;
+            // authority bump seed
+            let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
+
+            if **self.game_pool.lamports.borrow() < withdraw_funds {
+                return Err(error!(WinError::InsufficientGameMoney));
+            }
+
+            invoke_signed(
+                &system_instruction::transfer(
+                    self.game_pool.key,
+                    self.user_wallet.key,
+                    withdraw_funds
+                ),
+                &[
+                    self.game_pool.to_account_info().clone(),
+                    self.user_wallet.to_account_info().clone(),
+                    self.system_program.to_account_info().clone(),
+                ],
+                &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+            )?;
+        }
+
+        // the bid is gone afterwards, so a refund can't be taken twice
+        close_raw_account(&self.user_bid, &self.user_wallet)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// user close a bid PDA of a game in the old layout, refunding the ticket money(any token) of a cancelled game
+#[derive(Accounts)]
+pub struct RefundLegacyBidToken<'info> {
+    /// user wallet
+    #[account(mut)]
+    pub user_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// user token ata
+    #[account(
+        mut,
+        constraint = user_token_ata.owner == user_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub user_token_ata: Box<Account<'info, TokenAccount>>,
+
+    /// user bid PDA in the old layout
+    /// CHECK: verified in process
+    #[account(mut)]
+    pub user_bid: UncheckedAccount<'info>,
+
+    /// game pda in the old layout
+    /// CHECK: verified in process
+    pub game: UncheckedAccount<'info>,
+
+    /// Game Pool
+    /// CHECK: Safe account
+    #[account(
+        seeds = [GAME_POOL.as_ref()],
+        bump,
+    )]
+    pub game_pool: UncheckedAccount<'info>,
+
+    /// Game token Pool
+    #[account(
+        mut,
+        constraint = game_token_pool.owner == game_pool.key() @ WinError::InvalidTokenMint
+    )]
+    pub game_token_pool: Box<Account<'info, TokenAccount>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RefundLegacyBidToken<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let user_bid = LegacyUserBid::load(&self.user_bid, self.user_wallet.key(), self.game.key())?;
+        let game = LegacyGame::load(&self.game)?;
+        if game.coin_type != CoinType::TOKEN {
+            return Err(error!(WinError::InvalidAccount));
+        }
+        if self.user_token_ata.mint != game.ticket_token_address || self.game_token_pool.mint != game.ticket_token_address {
+            return Err(error!(WinError::InvalidTokenMint));
+        }
+        if !game.is_ended()? {
+            return Err(error!(WinError::GameIsNotCancelledStatus));
+        }
+
+        // a winner's global bid vouches for the prize return until every prize left the pool
+        if user_bid.bid_number == 1 && game.is_sold()? && !game.are_prizes_returned() {
+            return Err(error!(WinError::AccountInUse));
+        }
+
+        // global bid of a game that missed its minimum cost carries the refund
+        let is_refund = user_bid.bid_number == 1 &&
+            !game.is_sold()? &&
+            game.funds_status == FundsStatus::NotClaimed &&
+            user_bid.funds_status == FundsStatus::NotClaimed;
+        if is_refund && user_bid.gbl_total_ticket_amount > 0 {
+            let withdraw_funds = (user_bid.gbl_total_ticket_amount as u128).checked_mul(game.ticket_price as u128).ok_or(WinError::NumericalOverflow)? as u64;
+            // authority bump seed
+            let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
+
+            if self.game_token_pool.amount < withdraw_funds {
+                return Err(error!(WinError::InsufficientTokenBalance));
+            }
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    Transfer {
+                        from: self.game_token_pool.to_account_info(),
+                        to: self.user_token_ata.to_account_info(),
+                        authority: self.game_pool.to_account_info(),
+                    },
+                    &[&[GAME_POOL.as_ref(), &[bump_seed]]],
+                ),
+                withdraw_funds,
+            )?;
+        }
+
+        // the bid is gone afterwards, so a refund can't be taken twice
+        close_raw_account(&self.user_bid, &self.user_wallet)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use crate::globals::*;
+use anchor_lang::{prelude::*, solana_program::clock};
+use std::mem::size_of;
+use std::convert::TryInto;
+
+/// User roll refundable tickets of a cancelled game into its recreated game
+#[derive(Accounts)]
+pub struct RollBidIntoGame<'info> {
+    /// user
+    #[account(mut)]
+    pub user_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// old user bid PDA
+    #[account(
+        mut,
+        seeds = [USER_BID.as_ref(), b"1".as_ref(), &user_wallet.key().to_bytes(), &old_game.key().to_bytes()],
+        bump = old_user_global_bid.bump,
+        constraint = old_user_global_bid.funds_status == FundsStatus::NotClaimed @ WinError::ClaimedAlready,
+        constraint = old_user_global_bid.gbl_total_ticket_amount > 0 @ WinError::NoTicketAmount
+    )]
+    pub old_user_global_bid: Box<Account<'info, UserBid>>,
+
+    /// old game pda
+    #[account(
+        mut,
+        constraint = old_game.status == GameStatus::Recreated @ WinError::GameIsNotCancelledStatus
+    )]
+    pub old_game: Box<Account<'info, Game>>,
+
+    /// user bid PDA
+    #[account(
+        init,
+        seeds = [USER_BID.as_ref(), b"1".as_ref(), &user_wallet.key().to_bytes(), &game.key().to_bytes()],
+        bump,
+        payer = user_wallet,
+        space = 8 + size_of::<UserBid>(),
+    )]
+    pub user_global_bid: Box<Account<'info, UserBid>>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.previous_game == old_game.key() @ WinError::BidNotRollable,
+        constraint = game.coin_type == old_game.coin_type @ WinError::BidNotRollable,
+        constraint = game.ticket_token_address == old_game.ticket_token_address @ WinError::BidNotRollable,
+        constraint = game.pricing_mode == PricingMode::Fixed @ WinError::BidNotRollable
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// system program
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RollBidIntoGame<'info> {
+    pub fn process(&mut self, bump: u8) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status == GameStatus::Scheduled {
+            return Err(error!(WinError::GameIsNotStarted));
+        }
+        if self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
+        if self.game.prize_count == 0 {
+            return Err(error!(WinError::NoGamePrize));
+        }
+
+        // paid value stays in the pool and buys tickets at the new price
+        let paid_amount = self.old_user_global_bid.gbl_paid_amount;
+        if paid_amount % self.game.ticket_price != 0 {
+            return Err(error!(WinError::BidNotRollable));
+        }
+        let ticket_amount: u32 = paid_amount.checked_div(self.game.ticket_price).ok_or(WinError::NumericalOverflow)?.try_into().map_err(|_| WinError::NumericalOverflow)?;
+        self.game.verify_ticket_limits(0, ticket_amount, 0)?;
+
+        // current time, unix timestamp
+        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
+
+        self.user_global_bid.ticket_start_index = self.game.total_entries;
+        self.game.total_entries = self.game.total_entries.checked_add(ticket_amount).ok_or(WinError::NumericalOverflow)?;
+        self.game.total_bidders = self.game.total_bidders.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.entrant_index = self.game.entrant_count;
+        self.user_global_bid.entry_start_index = 0;
+        self.game.entrant_count = self.game.entrant_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.game.bid_account_count = self.game.bid_account_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.game.current_total_tickets = self.game.current_total_tickets.checked_add(ticket_amount).ok_or(WinError::NumericalOverflow)?;
+        self.game.total_paid = self.game.total_paid.checked_add(paid_amount).ok_or(WinError::NumericalOverflow)?;
+
+        self.user_global_bid.user_wallet = self.user_wallet.key();
+        self.user_global_bid.game_pda_address = self.game.key();
+        self.user_global_bid.opened_timestamp = current_time;
+        self.user_global_bid.gbl_total_ticket_amount = ticket_amount;
+        self.user_global_bid.gbl_entry_amount = ticket_amount;
+        self.user_global_bid.bought_ticket_amount = ticket_amount;
+        self.user_global_bid.bought_amount = paid_amount;
+        self.user_global_bid.gbl_paid_amount = paid_amount;
+        self.user_global_bid.bonus_ticket_amount = 0;
+        self.user_global_bid.freely_ticket_amount = 0;
+        self.user_global_bid.gbl_bonus_ticket_amount = 0;
+        self.user_global_bid.gbl_has_distirbuted_bonus = false;
+        self.user_global_bid.total_bid_number = 1;
+        self.user_global_bid.bid_number = 1;
+        self.user_global_bid.funds_status = FundsStatus::NotClaimed;
+        self.user_global_bid.winner_nft_claim = false;
+        self.user_global_bid.is_winner = false;
+        self.user_global_bid.bump = bump;
+
+        // rolled tickets leave the old game like a refund
+        self.old_user_global_bid.funds_status = FundsStatus::Retransfer;
+        self.old_game.current_total_tickets = self.old_game.current_total_tickets.checked_sub(self.old_user_global_bid.gbl_total_ticket_amount).ok_or(WinError::NumericalOverflow)?;
+        self.old_game.total_bidders = self.old_game.total_bidders.checked_sub(1).ok_or(WinError::NumericalOverflow)?;
+        self.old_game.total_paid = self.old_game.total_paid.checked_sub(paid_amount).ok_or(WinError::NumericalOverflow)?;
+
+        self.game.apply_anti_snipe()?;
+        self.game.close_if_sold_out()?;
+        Ok(())
+    }
+}
@@ -35,6 +35,7 @@ pub struct UserGameBidToken<'info> {
         mut,
         seeds = [USER_BID.as_ref(), b"1".as_ref(), &user_wallet.key().to_bytes(), &game.key().to_bytes()],
         bump = user_global_bid.bump,
+        constraint = user_global_bid.funds_status == FundsStatus::NotClaimed @ WinError::ClaimedAlready
     )]
     pub user_global_bid: Box<Account<'info, UserBid>>,
 
@@ -50,10 +51,7 @@ pub struct UserGameBidToken<'info> {
     pub user_bid: Box<Account<'info, UserBid>>,
 
     /// game pda
-    #[account(
-        mut,
-        constraint = game.verify_game_time() @ WinError::GameIsNotOpenedStatus
-    )]
+    #[account(mut)]
     pub game: Box<Account<'info, Game>>,
 
     /// Game SOL Pool
@@ -98,9 +96,17 @@ pub struct UserGameBidToken<'info> {
 
 impl<'info> UserGameBidToken<'info> {
     pub fn process(&mut self, args: UserGameBidTokenArgs) -> Result<()> {
+        self.game.update_status()?;
+        if self.game.status == GameStatus::Scheduled {
+            return Err(error!(WinError::GameIsNotStarted));
+        }
+        if self.game.status != GameStatus::Open {
+            return Err(error!(WinError::GameIsNotOpenedStatus));
+        }
         if args.ticket_amount.checked_add(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)? == 0 {
             return Err(error!(WinError::NoTicketAmount));
         }
+        self.game.verify_ticket_limits(self.user_global_bid.gbl_entry_amount, args.ticket_amount, args.bonus_ticket_amount)?;
 
         // current time, unix timestamp
         let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
@@ -117,22 +123,29 @@ impl<'info> UserGameBidToken<'info> {
             return Err(error!(WinError::InvalidBonusTicketAmount));
         }
 
-        let ticket_price = (args.ticket_amount as u128).checked_mul(self.game.ticket_price as u128).ok_or(WinError::NumericalOverflow)? as u64;
+        let ticket_price = self.game.purchase_cost(args.ticket_amount)?;
         if self.user_token_ata.amount < ticket_price {
             return Err(error!(WinError::InsufficientTokenBalance));
         }
+        self.user_bid.ticket_start_index = self.game.total_entries;
+        self.game.total_entries = self.game.total_entries.checked_add(args.ticket_amount).ok_or(WinError::NumericalOverflow)?.checked_add(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?;
         self.game.current_total_tickets = self.game.current_total_tickets.checked_add(args.ticket_amount).ok_or(WinError::NumericalOverflow)?;
         self.game.current_total_bonus_tickets = self.game.current_total_bonus_tickets.checked_add(args.bonus_ticket_amount as u32).ok_or(WinError::NumericalOverflow)?;
-        self.game.winner_random_number = args.random_number;
         
         self.user_global_bid.gbl_total_ticket_amount = self.user_global_bid.gbl_total_ticket_amount.checked_add(args.ticket_amount).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.gbl_entry_amount = self.user_global_bid.gbl_entry_amount.checked_add(args.ticket_amount).ok_or(WinError::NumericalOverflow)?.checked_add(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?;
         self.user_global_bid.total_bid_number = args.bid_number;
+        self.user_global_bid.gbl_paid_amount = self.user_global_bid.gbl_paid_amount.checked_add(ticket_price).ok_or(WinError::NumericalOverflow)?;
+        self.game.total_paid = self.game.total_paid.checked_add(ticket_price).ok_or(WinError::NumericalOverflow)?;
+        self.game.bid_account_count = self.game.bid_account_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
 
         self.user_bid.user_wallet = self.user_wallet.key();
         self.user_bid.game_pda_address = self.game.key();
         self.user_bid.opened_timestamp = current_time;
         self.user_bid.gbl_total_ticket_amount = 0;
         self.user_bid.bought_ticket_amount = args.ticket_amount;
+        self.user_bid.bought_amount = ticket_price;
+        self.user_bid.gbl_paid_amount = 0;
         self.user_bid.gbl_bonus_ticket_amount = 0;
 
         if self.user_global_bid.gbl_has_distirbuted_bonus {
@@ -150,12 +163,14 @@ impl<'info> UserGameBidToken<'info> {
         } else {
             self.user_bid.bonus_ticket_amount = 0;
             self.user_bid.freely_ticket_amount = args.bonus_ticket_amount;
+            self.user_details.freely_ticket_amount = self.user_details.freely_ticket_amount.checked_sub(args.bonus_ticket_amount).ok_or(WinError::NumericalOverflow)?;
         }
         
         self.user_bid.total_bid_number = 0;
         self.user_bid.bid_number = args.bid_number;
         self.user_bid.funds_status = FundsStatus::NotClaimed;
         self.user_bid.winner_nft_claim = false;
+        self.user_bid.is_winner = false;
         self.user_bid.gbl_has_distirbuted_bonus = false;
         
         
@@ -174,7 +189,7 @@ impl<'info> UserGameBidToken<'info> {
         }
 
         if !self.user_global_bid.gbl_has_distirbuted_bonus {
-            let total_ticket_price = (self.user_global_bid.gbl_total_ticket_amount as u128).checked_mul(self.game.ticket_price as u128).ok_or(WinError::NumericalOverflow)? as u64;
+            let total_ticket_price = self.user_global_bid.gbl_paid_amount;
 
             if total_ticket_price >= (self.win.token_amount_for_bonus_tickets as u128).checked_mul(self.game.ticket_price as u128).ok_or(WinError::NumericalOverflow)? as u64 {
                 self.user_global_bid.gbl_bonus_ticket_amount = self.win.game_bonus_ticket_amount;
@@ -182,6 +197,8 @@ impl<'info> UserGameBidToken<'info> {
             }
         }
 
+        self.game.apply_anti_snipe()?;
+        self.game.close_if_sold_out()?;
         Ok(())
     }
 }
@@ -194,9 +211,6 @@ pub struct UserGameBidTokenArgs {
     /// bonus ticket amount,
     pub bonus_ticket_amount: u32,
 
-    /// winner random number
-    pub random_number: [u32;6],
-
     /// current bid number
     pub bid_number: u8,
 }
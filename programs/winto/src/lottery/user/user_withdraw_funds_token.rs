@@ -39,6 +39,7 @@ pub struct UserWithdrawFundsToken<'info> {
     pub user_global_bid: Box<Account<'info, UserBid>>,
 
     /// game pda
+    #[account(mut)]
     pub game: Box<Account<'info, Game>>,
 
     /// Game Pool
@@ -76,13 +77,19 @@ pub struct UserWithdrawFundsToken<'info> {
 impl<'info> UserWithdrawFundsToken<'info> {
     pub fn process(&mut self) -> Result<()> {
         let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
-        let total_earn = (self.game.ticket_price as u128).checked_mul(self.game.current_total_tickets as u128).ok_or(WinError::NumericalOverflow)? as u64;
+        self.game.update_status()?;
 
-        if total_earn >= self.game.minimum_cost || current_time < self.game.opened_timestamp.checked_add(self.game.duration).ok_or(WinError::NumericalOverflow)? {
+        // bidders can opt out of an extended game during its refund window
+        let is_opt_out = self.game.status == GameStatus::Open && current_time < self.game.refund_window_end;
+        if self.game.status != GameStatus::Cancelled && self.game.status != GameStatus::Recreated && !is_opt_out {
             return Err(error!(WinError::GameIsNotCancelledStatus));
         }
+        // ticket money the organizer already collected can not be refunded again
+        if self.game.funds_status == FundsStatus::Withdrawed {
+            return Err(error!(WinError::ClaimedAlready));
+        }
         if self.user_global_bid.gbl_total_ticket_amount > 0 {
-            let withdraw_funds = (self.user_global_bid.gbl_total_ticket_amount as u128).checked_mul(self.game.ticket_price as u128).ok_or(WinError::NumericalOverflow)? as u64;
+            let withdraw_funds = self.user_global_bid.gbl_paid_amount;
             // authority bump seed
             let (_pda, bump_seed) = Pubkey::find_program_address(&[GAME_POOL.as_ref()], &crate::ID);
             
@@ -104,6 +111,11 @@ impl<'info> UserWithdrawFundsToken<'info> {
             )?;
 
             self.user_global_bid.funds_status = FundsStatus::Withdrawed;
+
+            // refunded entries stay in the ticket sequence but no longer count
+            self.game.current_total_tickets = self.game.current_total_tickets.checked_sub(self.user_global_bid.gbl_total_ticket_amount).ok_or(WinError::NumericalOverflow)?;
+            self.game.total_bidders = self.game.total_bidders.checked_sub(1).ok_or(WinError::NumericalOverflow)?;
+            self.game.total_paid = self.game.total_paid.checked_sub(withdraw_funds).ok_or(WinError::NumericalOverflow)?;
         }
 
         Ok(())
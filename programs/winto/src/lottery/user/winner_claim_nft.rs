@@ -1,5 +1,5 @@
 use crate::globals::*;
-use anchor_lang::{prelude::*, solana_program::clock};
+use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer, TokenAccount, Token, Mint};
 
 /// Winner get NFT
@@ -15,6 +15,9 @@ pub struct WinnerClaimNft<'info> {
     pub bot_wallet: Signer<'info>,
 
     /// winner NFT
+    #[account(
+        constraint = winner_nft.key() == game_prize.mint_address @ WinError::InvalidTokenMint
+    )]
     pub winner_nft: Box<Account<'info, Mint>>,
 
     /// winner nft ata
@@ -42,14 +45,36 @@ pub struct WinnerClaimNft<'info> {
     pub nft_pool_ata: Box<Account<'info, TokenAccount>>,
 
     /// game pda
+    #[account(
+        mut,
+        constraint = game.status == GameStatus::Settled @ WinError::NotDrawnYet
+    )]
     pub game: Box<Account<'info, Game>>,
 
+    /// game prize pda
+    #[account(
+        mut,
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game_prize.index.to_le_bytes()],
+        bump = game_prize.bump,
+        constraint = game_prize.is_claimed == false @ WinError::NftAlreadyClaimed,
+        constraint = game_prize.prize_type == PrizeType::Nft @ WinError::InvalidPrizeType
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// game prize pda holding the winner of the prize's winner slot; same as game prize for slot prizes
+    #[account(
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game.prize_winner_slot(game_prize.index).to_le_bytes()],
+        bump = winner_slot_prize.bump,
+        constraint = winner_slot_prize.winner_wallet == winner_wallet.key() @ WinError::NotWinner
+    )]
+    pub winner_slot_prize: Box<Account<'info, GamePrize>>,
+
     /// user bid PDA
     #[account(
         mut,
         seeds = [USER_BID.as_ref(), b"1".as_ref(), &winner_wallet.key().to_bytes(), &game.key().to_bytes()],
         bump = user_global_bid.bump,
-        constraint = user_global_bid.winner_nft_claim == false @ WinError::NftAlreadyClaimed
+        constraint = user_global_bid.is_winner @ WinError::NotWinner
     )]
     pub user_global_bid: Box<Account<'info, UserBid>>,
 
@@ -67,40 +92,25 @@ pub struct WinnerClaimNft<'info> {
 
 impl<'info> WinnerClaimNft<'info> {
     pub fn process(&mut self) -> Result<()> {
-        let current_time = clock::Clock::get().unwrap().unix_timestamp as u32;
-        let total_earn = (self.game.ticket_price as u128).checked_mul(self.game.current_total_tickets as u128).ok_or(WinError::NumericalOverflow)? as u64;
+        // authority bump seed
+        let (_pda, bump_seed) = Pubkey::find_program_address(&[NFT_POOL.as_ref()], &crate::ID);
 
-        if total_earn >= self.game.minimum_cost && current_time > self.game.opened_timestamp.checked_add(self.game.duration).ok_or(WinError::NumericalOverflow)? {
-            let mut nft_exist = false;
-            for nft in self.game.nft_mint_address.iter() {
-                if nft == &self.winner_nft.key() {
-                    nft_exist = true;
-                }
-            }
-            if nft_exist {
-                // authority bump seed
-                let (_pda, bump_seed) = Pubkey::find_program_address(&[NFT_POOL.as_ref()], &crate::ID);
-    
-                token::transfer(
-                    CpiContext::new_with_signer(
-                        self.token_program.to_account_info(),
-                        Transfer {
-                            from: self.nft_pool_ata.to_account_info(),
-                            to: self.winner_nft_ata.to_account_info(),
-                            authority: self.nft_pool.to_account_info(),
-                        },
-                        &[&[NFT_POOL.as_ref(), &[bump_seed]]],
-                    ),
-                    1,
-                )?;
-                self.user_global_bid.winner_nft_claim = true;
-            } else {
-                return Err(error!(WinError::AccessDenied));
-            }
-        } else {
-            return Err(error!(WinError::GameIsNotClosedStatus));
-        }
-        
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.nft_pool_ata.to_account_info(),
+                    to: self.winner_nft_ata.to_account_info(),
+                    authority: self.nft_pool.to_account_info(),
+                },
+                &[&[NFT_POOL.as_ref(), &[bump_seed]]],
+            ),
+            1,
+        )?;
+        self.game_prize.is_claimed = true;
+        self.game.claimed_prize_count = self.game.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.claimed_prize_count = self.user_global_bid.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.winner_nft_claim = true;
 
         Ok(())
     }
@@ -0,0 +1,75 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+
+/// Winner get SOL prize
+#[derive(Accounts)]
+pub struct WinnerClaimSol<'info> {
+    /// winner
+    #[account(mut)]
+    pub winner_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.status == GameStatus::Settled @ WinError::NotDrawnYet
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda
+    #[account(
+        mut,
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game_prize.index.to_le_bytes()],
+        bump = game_prize.bump,
+        constraint = game_prize.is_claimed == false @ WinError::NftAlreadyClaimed,
+        constraint = game_prize.prize_type == PrizeType::Sol @ WinError::InvalidPrizeType
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// game prize pda holding the winner of the prize's winner slot; same as game prize for slot prizes
+    #[account(
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game.prize_winner_slot(game_prize.index).to_le_bytes()],
+        bump = winner_slot_prize.bump,
+        constraint = winner_slot_prize.winner_wallet == winner_wallet.key() @ WinError::NotWinner
+    )]
+    pub winner_slot_prize: Box<Account<'info, GamePrize>>,
+
+    /// user bid PDA
+    #[account(
+        mut,
+        seeds = [USER_BID.as_ref(), b"1".as_ref(), &winner_wallet.key().to_bytes(), &game.key().to_bytes()],
+        bump = user_global_bid.bump,
+        constraint = user_global_bid.is_winner @ WinError::NotWinner
+    )]
+    pub user_global_bid: Box<Account<'info, UserBid>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+}
+
+impl<'info> WinnerClaimSol<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // the game prize pda holds the lamports on top of its rent and stays rent exempt
+        let game_prize_info = self.game_prize.to_account_info();
+        let winner_info = self.winner_wallet.to_account_info();
+        **game_prize_info.try_borrow_mut_lamports()? = game_prize_info.lamports().checked_sub(self.game_prize.amount).ok_or(WinError::InsufficientSolBalance)?;
+        **winner_info.try_borrow_mut_lamports()? = winner_info.lamports().checked_add(self.game_prize.amount).ok_or(WinError::NumericalOverflow)?;
+
+        self.game_prize.is_claimed = true;
+        self.game.claimed_prize_count = self.game.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.claimed_prize_count = self.user_global_bid.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.winner_nft_claim = true;
+
+        Ok(())
+    }
+}
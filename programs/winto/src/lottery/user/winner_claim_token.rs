@@ -0,0 +1,117 @@
+use crate::globals::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, TokenAccount, Token, Mint};
+
+/// Winner get token prize
+#[derive(Accounts)]
+pub struct WinnerClaimToken<'info> {
+    /// winner
+    pub winner_wallet: Signer<'info>,
+
+    /// bot wallet
+    #[account(
+        constraint = bot_wallet.key() == win.bot_wallet @ WinError::AccessDenied
+    )]
+    pub bot_wallet: Signer<'info>,
+
+    /// prize token mint
+    #[account(
+        constraint = prize_mint.key() == game_prize.mint_address @ WinError::InvalidTokenMint
+    )]
+    pub prize_mint: Box<Account<'info, Mint>>,
+
+    /// winner token ata
+    #[account(
+        mut,
+        constraint = winner_token_ata.mint == prize_mint.key() @ WinError::InvalidTokenMint,
+        constraint = winner_token_ata.owner == winner_wallet.key() @ WinError::InvalidTokenOwner
+    )]
+    pub winner_token_ata: Box<Account<'info, TokenAccount>>,
+
+    /// NFT Pool
+    /// CHECK: Safe account
+    #[account(
+        seeds = [NFT_POOL.as_ref()],
+        bump
+    )]
+    pub nft_pool: UncheckedAccount<'info>,
+
+    /// NFT Pool ata of prize token
+    #[account(
+        mut,
+        constraint = nft_pool_ata.mint == prize_mint.key() @ WinError::InvalidTokenMint,
+        constraint = nft_pool_ata.owner == nft_pool.key() @ WinError::InvalidTokenOwner
+    )]
+    pub nft_pool_ata: Box<Account<'info, TokenAccount>>,
+
+    /// game pda
+    #[account(
+        mut,
+        constraint = game.status == GameStatus::Settled @ WinError::NotDrawnYet
+    )]
+    pub game: Box<Account<'info, Game>>,
+
+    /// game prize pda
+    #[account(
+        mut,
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game_prize.index.to_le_bytes()],
+        bump = game_prize.bump,
+        constraint = game_prize.is_claimed == false @ WinError::NftAlreadyClaimed,
+        constraint = game_prize.prize_type == PrizeType::Token @ WinError::InvalidPrizeType
+    )]
+    pub game_prize: Box<Account<'info, GamePrize>>,
+
+    /// game prize pda holding the winner of the prize's winner slot; same as game prize for slot prizes
+    #[account(
+        seeds = [GAME_PRIZE.as_ref(), &game.prize_game.to_bytes(), &game.prize_winner_slot(game_prize.index).to_le_bytes()],
+        bump = winner_slot_prize.bump,
+        constraint = winner_slot_prize.winner_wallet == winner_wallet.key() @ WinError::NotWinner
+    )]
+    pub winner_slot_prize: Box<Account<'info, GamePrize>>,
+
+    /// user bid PDA
+    #[account(
+        mut,
+        seeds = [USER_BID.as_ref(), b"1".as_ref(), &winner_wallet.key().to_bytes(), &game.key().to_bytes()],
+        bump = user_global_bid.bump,
+        constraint = user_global_bid.is_winner @ WinError::NotWinner
+    )]
+    pub user_global_bid: Box<Account<'info, UserBid>>,
+
+    /// Win details PDA
+    #[account(
+        seeds = [WIN.as_ref()],
+        bump = win.bump,
+        constraint = win.is_emergency_flag == false @ WinError::EmergencyStatus,
+    )]
+    pub win: Box<Account<'info, Win>>,
+
+    /// token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WinnerClaimToken<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // authority bump seed
+        let (_pda, bump_seed) = Pubkey::find_program_address(&[NFT_POOL.as_ref()], &crate::ID);
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.nft_pool_ata.to_account_info(),
+                    to: self.winner_token_ata.to_account_info(),
+                    authority: self.nft_pool.to_account_info(),
+                },
+                &[&[NFT_POOL.as_ref(), &[bump_seed]]],
+            ),
+            self.game_prize.amount,
+        )?;
+        self.game_prize.is_claimed = true;
+        self.game.claimed_prize_count = self.game.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.claimed_prize_count = self.user_global_bid.claimed_prize_count.checked_add(1).ok_or(WinError::NumericalOverflow)?;
+        self.user_global_bid.winner_nft_claim = true;
+
+        Ok(())
+    }
+}